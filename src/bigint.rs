@@ -0,0 +1,261 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul},
+};
+
+/// Bit width of a limb.
+pub const LIMB_BITS: usize = 64;
+
+/// Largest power of ten that fits into a limb, used for decimal conversion.
+const DECIMAL_BASE: u64 = 10_000_000_000_000_000_000;
+
+/// Number of decimal digits in `DECIMAL_BASE`.
+const DECIMAL_DIGITS: usize = 19;
+
+/// Arbitrary-precision unsigned integer.
+/// Stored as u64 limbs, least significant limb first, without trailing zero
+/// limbs, so zero has no limbs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BigUint {
+    /// Little-endian limbs.
+    limbs: Vec<u64>,
+}
+
+/// Arbitrary-precision unsigned integer implementation.
+impl BigUint {
+    /// Creates an integer from little-endian limbs.
+    pub fn from_limbs(limbs: &[u64]) -> Self {
+        let mut number = Self {
+            limbs: limbs.to_vec(),
+        };
+        number.normalize();
+        number
+    }
+
+    /// Parses a decimal string, returns `None` if it is empty or contains
+    /// anything but ASCII digits.
+    pub fn from_decimal(input: &str) -> Option<Self> {
+        if input.is_empty() || !input.bytes().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        // fold the digits in, one at a time
+        let mut number = Self::default();
+        for digit in input.bytes() {
+            number.mul_add_small(10, (digit - b'0') as u64);
+        }
+
+        Some(number)
+    }
+
+    /// Parses a hexadecimal string without `0x` prefix, returns `None` if it
+    /// is empty or contains anything but ASCII hex digits.
+    pub fn from_hex(input: &str) -> Option<Self> {
+        if input.is_empty() {
+            return None;
+        }
+
+        // fold the digits in, one at a time
+        let mut number = Self::default();
+        for digit in input.chars() {
+            number.mul_add_small(16, digit.to_digit(16)? as u64);
+        }
+
+        Some(number)
+    }
+
+    /// Returns the little-endian limbs, without trailing zero limbs.
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    /// Returns exactly `count` little-endian limbs, padded with zero limbs, or
+    /// `None` if the integer does not fit.
+    pub fn to_limbs(&self, count: usize) -> Option<Vec<u64>> {
+        if self.limbs.len() > count {
+            return None;
+        }
+
+        let mut limbs = self.limbs.clone();
+        limbs.resize(count, 0);
+        Some(limbs)
+    }
+
+    /// Computes `self·factor + addend` in place.
+    fn mul_add_small(&mut self, factor: u64, addend: u64) {
+        let mut carry = addend as u128;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u128 * factor as u128 + carry;
+            *limb = value as u64;
+            carry = value >> LIMB_BITS;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u64);
+        }
+        self.normalize();
+    }
+
+    /// Divides by a small divisor in place and returns the remainder.
+    pub fn div_rem_small(&mut self, divisor: u64) -> u64 {
+        let mut remainder = 0u128;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (remainder << LIMB_BITS) | *limb as u128;
+            *limb = (value / divisor as u128) as u64;
+            remainder = value % divisor as u128;
+        }
+        self.normalize();
+        remainder as u64
+    }
+
+    /// Drops trailing zero limbs.
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+/// Orders by magnitude, limbs are normalized so longer means larger.
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+/// Orders by magnitude.
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Converts a u64 into an arbitrary-precision integer.
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from_limbs(&[value])
+    }
+}
+
+/// Schoolbook addition, carrying between limbs.
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let len = self.limbs.len().max(rhs.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+
+        let mut carry = 0u128;
+        for i in 0..len {
+            let lhs = self.limbs.get(i).copied().unwrap_or(0) as u128;
+            let rhs = rhs.limbs.get(i).copied().unwrap_or(0) as u128;
+            let value = lhs + rhs + carry;
+            limbs.push(value as u64);
+            carry = value >> LIMB_BITS;
+        }
+        limbs.push(carry as u64);
+
+        BigUint::from_limbs(&limbs)
+    }
+}
+
+/// Schoolbook multiplication, carrying between limbs.
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = vec![0u64; self.limbs.len() + rhs.limbs.len()];
+
+        for (i, lhs) in self.limbs.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, rhs) in rhs.limbs.iter().enumerate() {
+                let value = *lhs as u128 * *rhs as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = value as u64;
+                carry = value >> LIMB_BITS;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u64;
+        }
+
+        BigUint::from_limbs(&limbs)
+    }
+}
+
+/// Formats the integer in decimal.
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // split into base 10^19 chunks, least significant first
+        let mut number = self.clone();
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(number.div_rem_small(DECIMAL_BASE));
+            if number.limbs.is_empty() {
+                break;
+            }
+        }
+
+        // the most significant chunk is not padded
+        let mut chunks = chunks.into_iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(0))?;
+        for chunk in chunks {
+            write!(f, "{:0width$}", chunk, width = DECIMAL_DIGITS)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(input: &str) -> BigUint {
+        BigUint::from_decimal(input).unwrap()
+    }
+
+    #[test]
+    fn test_decimal() {
+        for input in [
+            "0",
+            "1",
+            "18446744073709551616",
+            "10000000000000000000",
+            "340282366920938463463374607431768211455",
+        ] {
+            assert_eq!(big(input).to_string(), input);
+        }
+
+        assert_eq!(big("000123").to_string(), "123");
+        assert_eq!(big("18446744073709551616").limbs(), &[0, 1]);
+        assert_eq!(BigUint::from_decimal(""), None);
+        assert_eq!(BigUint::from_decimal("12a"), None);
+
+        assert_eq!(BigUint::from_hex("ff").unwrap().to_string(), "255");
+        assert_eq!(BigUint::from_hex("1_0"), None);
+        assert!(big("18446744073709551616") > big("18446744073709551615"));
+        assert!(big("18446744073709551617") > big("18446744073709551616"));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        // differential test against u128 arithmetic
+        for (a, b) in [
+            (0u64, 0u64),
+            (1, u64::MAX),
+            (u64::MAX, u64::MAX),
+            (0x9e37_79b9_7f4a_7c15, 1 << 63),
+        ] {
+            let (x, y) = (BigUint::from(a), BigUint::from(b));
+            assert_eq!((&x + &y).to_string(), (a as u128 + b as u128).to_string());
+            assert_eq!((&x * &y).to_string(), (a as u128 * b as u128).to_string());
+        }
+
+        // 2^128 · 2^128 = 2^256, past the field modulus
+        let x = big("340282366920938463463374607431768211456");
+        assert_eq!((&x * &x).limbs(), &[0, 0, 0, 0, 1]);
+        assert_eq!(x.to_limbs(3), Some(vec![0, 0, 1]));
+        assert_eq!(x.to_limbs(2), None);
+    }
+}
@@ -0,0 +1,220 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    bigint::{BigUint, LIMB_BITS},
+    chips::{
+        arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input},
+        bigint::BigIntInstructions,
+        range::RangeInstructions,
+    },
+    expression::BigExpression,
+    zk_calculator::Operator,
+};
+
+/// Default number of limbs of a private variable, 256 bits cover any field
+/// element.
+pub const DEFAULT_VARIABLE_LIMBS: usize = 4;
+
+/// Big integer circuit definition.
+///
+/// Computes a `+` and `*` expression over arbitrary-precision integers as u64
+/// limbs, so results larger than the field modulus are exact. The limbs of
+/// the result, least significant first, are the public inputs.
+pub struct BigIntCircuit {
+    /// Expression to compute.
+    pub expression: BigExpression,
+    /// Private variable values.
+    pub variables: Vec<Value<BigUint>>,
+    /// Number of limbs of each private variable. Part of the circuit shape,
+    /// so it is public.
+    pub variable_limbs: usize,
+}
+
+/// Big integer circuit implementation.
+impl<F: FieldExt> Circuit<F> for BigIntCircuit {
+    // reuse the top-level config
+    type Config = ArithmeticConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            expression: self.expression.clone(),
+            variables: vec![Value::unknown(); self.variables.len()],
+            variable_limbs: self.variable_limbs,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // get advice columns
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        // get instance column
+        let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
+
+        // reuse the ArithmeticChip configuration and return
+        ArithmeticChip::configure(meta, a, b, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // construct the arithmetic chip
+        let arithmetic_chip = ArithmeticChip::<F>::construct(config, ());
+
+        // witness each limb of each variable directly into its range check
+        let mut variables = Vec::new();
+        for value in &self.variables {
+            let mut limbs = Vec::new();
+            for i in 0..self.variable_limbs {
+                let limb = value
+                    .as_ref()
+                    .map(|v| F::from(v.limbs().get(i).copied().unwrap_or(0)));
+                let limb =
+                    arithmetic_chip.range_check(&mut layouter, Input::Private(limb), LIMB_BITS)?;
+                limbs.push(Input::from(limb));
+            }
+            variables.push(limbs);
+        }
+
+        // compile the expression
+        let c = Self::compile(
+            &arithmetic_chip,
+            &mut layouter,
+            &self.expression,
+            &variables,
+        )?;
+
+        // expose each limb of the result
+        for (row, limb) in c.into_iter().enumerate() {
+            let limb = match limb {
+                Input::Number(limb) => limb,
+                Input::Private(limb) => {
+                    arithmetic_chip.load_private(layouter.namespace(|| "load limb"), limb)?
+                }
+                // a lone constant is not consumed by any instruction, so load
+                // it
+                Input::Constant(limb) => {
+                    arithmetic_chip.load_constant(layouter.namespace(|| "load limb"), limb)?
+                }
+            };
+            arithmetic_chip.expose_public(layouter.namespace(|| "expose limb"), limb, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Big integer circuit compiler.
+impl BigIntCircuit {
+    /// Compiles an expression into big integer instructions and returns the
+    /// limbs of its result.
+    fn compile<F: FieldExt>(
+        arithmetic_chip: &ArithmeticChip<F>,
+        layouter: &mut impl Layouter<F>,
+        expression: &BigExpression,
+        variables: &[Vec<Input<F>>],
+    ) -> Result<Vec<Input<F>>, Error> {
+        match expression {
+            BigExpression::Constant(constant) => {
+                let limbs = constant.limbs().len().max(1);
+                Ok(constant
+                    .to_limbs(limbs)
+                    .ok_or(Error::Synthesis)?
+                    .into_iter()
+                    .map(|limb| Input::Constant(F::from(limb)))
+                    .collect())
+            }
+            BigExpression::Variable(index) => Ok(variables[*index].clone()),
+            BigExpression::Binary(operator, lhs, rhs) => {
+                let a = Self::compile(arithmetic_chip, layouter, lhs, variables)?;
+                let b = Self::compile(arithmetic_chip, layouter, rhs, variables)?;
+
+                let c = match operator {
+                    Operator::Add => arithmetic_chip.big_add(layouter, &a, &b)?,
+                    Operator::Mul => arithmetic_chip.big_mul(layouter, &a, &b)?,
+                    // the parser only accepts `+` and `*`
+                    _ => return Err(Error::Synthesis),
+                };
+
+                Ok(c.into_iter().map(Input::from).collect::<Vec<_>>())
+            }
+        }
+    }
+}
+
+/// Returns the limbs of the result of a big integer expression as public
+/// inputs, in the layout `BigIntCircuit` exposes them.
+pub fn public_inputs<F: FieldExt>(
+    expression: &BigExpression,
+    variables: &[BigUint],
+    variable_limbs: usize,
+) -> Option<Vec<F>> {
+    let limbs = expression.limbs(variable_limbs);
+    let c = expression.evaluate(variables).to_limbs(limbs)?;
+
+    Some(c.into_iter().map(F::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expression::parse_big;
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    fn build(input: &str, values: &[&str]) -> (BigIntCircuit, Vec<Fp>) {
+        let (expression, variables) = parse_big(input).unwrap();
+        assert_eq!(variables.len(), values.len());
+
+        let values: Vec<BigUint> = values
+            .iter()
+            .map(|v| BigUint::from_decimal(v).unwrap())
+            .collect();
+        let public_inputs = public_inputs(&expression, &values, DEFAULT_VARIABLE_LIMBS).unwrap();
+
+        let circuit = BigIntCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
+            variable_limbs: DEFAULT_VARIABLE_LIMBS,
+        };
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_bigint() {
+        let k = 12;
+
+        // the Pallas base field modulus minus one, squared, plus a constant
+        // past the field
+        let p = "28948022309329048855892746252171976963363056481941560715954676764349967630336";
+        for (input, values) in [
+            (
+                "?a * ?b + 340282366920938463463374607431768211456",
+                vec![p, p],
+            ),
+            ("(?a + ?b) * ?a", vec!["18446744073709551615", "1"]),
+            ("?a * ?a * ?a", vec![p]),
+            ("7 * 6", vec![]),
+        ] {
+            let (circuit, public_inputs) = build(input, &values);
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // a wrong limb is not accepted
+            let mut wrong = public_inputs;
+            wrong[0] += Fp::one();
+
+            let prover = MockProver::run(k, &circuit, vec![wrong]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+}
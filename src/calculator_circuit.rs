@@ -6,19 +6,45 @@ use halo2_proofs::{
 
 use crate::{
     chips::{
-        add::AddInstructions,
-        arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions},
-        mul::MulInstructions,
+        abs::AbsInstructions,
+        add::{AddConstantInstructions, AddInstructions},
+        arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input},
+        bitwise::{BitwiseInstructions, ShiftInstructions},
+        compare::CompareInstructions,
+        div::DivInstructions,
+        fixed::FixedPointInstructions,
+        fma::FmaInstructions,
+        gcd::GcdInstructions,
+        logic::LogicInstructions,
+        min_max::MinMaxInstructions,
+        modular::ModularInstructions,
+        mul::{MulConstantInstructions, MulInstructions},
+        pow::PowInstructions,
+        private_pow::PrivatePowInstructions,
+        saturating::SaturatingInstructions,
+        select::SelectInstructions,
+        sqrt::SqrtInstructions,
         sub::SubInstructions,
+        wrapping::WrappingInstructions,
     },
-    zk_calculator::Operator,
+    expression::Expression,
+    field,
+    zk_calculator::{Function, Operand, Operator},
 };
 
+/// Default maximum bit width of a private exponent, wide enough for any u64.
+pub const DEFAULT_EXPONENT_BITS: usize = 64;
+
 /// Calculator circuit definition.
 pub struct CalculatorCircuit<F: FieldExt> {
-    pub a: Value<F>,
-    pub b: Value<F>,
-    pub operator: Operator,
+    /// Expression to compute.
+    pub expression: Expression,
+    /// Private variables referenced by the expression.
+    pub variables: Vec<Value<F>>,
+    /// Maximum bit width of a private exponent. Each private exponent is
+    /// decomposed into exactly this many bits, so the circuit shape does not
+    /// depend on its value.
+    pub exponent_bits: usize,
 }
 
 /// Calculator circuit implementation.
@@ -29,9 +55,9 @@ impl<F: FieldExt> Circuit<F> for CalculatorCircuit<F> {
 
     fn without_witnesses(&self) -> Self {
         Self {
-            a: Value::default(),
-            b: Value::default(),
-            operator: self.operator.clone(),
+            expression: self.expression.clone(),
+            variables: vec![Value::default(); self.variables.len()],
+            exponent_bits: self.exponent_bits,
         }
     }
 
@@ -41,9 +67,11 @@ impl<F: FieldExt> Circuit<F> for CalculatorCircuit<F> {
         let b = meta.advice_column();
         // get instance column
         let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
 
         // reuse the ArithmeticChip configuration and return
-        ArithmeticChip::configure(meta, a, b, instance)
+        ArithmeticChip::configure(meta, a, b, instance, constant)
     }
 
     fn synthesize(
@@ -54,24 +82,402 @@ impl<F: FieldExt> Circuit<F> for CalculatorCircuit<F> {
         // construct the arithmetic chip
         let arithmetic_chip = ArithmeticChip::<F>::construct(config, ());
 
-        // load private values into the circuit
-        let a = arithmetic_chip.load_private(layouter.namespace(|| "load a"), self.a)?;
-        let b = arithmetic_chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+        // the byte table is only needed by bitwise operators
+        if self.expression.any_operator(&Operator::is_bitwise) {
+            arithmetic_chip.load_bitwise_table(layouter.namespace(|| "load byte table"))?;
+        }
+
+        // count the uses of each variable
+        let mut uses = vec![0; self.variables.len()];
+        self.expression.count_uses(&mut uses);
 
-        let c = match &self.operator {
-            Operator::Add => arithmetic_chip.add(&mut layouter, a, b),
-            Operator::Sub => arithmetic_chip.sub(&mut layouter, a, b),
-            Operator::Mul => arithmetic_chip.mul(&mut layouter, a, b),
-        }?;
+        // variables used once are witnessed directly into the region of the
+        // instruction that consumes them, reused variables are loaded once
+        // and copied into each instruction
+        let variables = self
+            .variables
+            .iter()
+            .zip(uses)
+            .map(|(value, uses)| match uses {
+                0 | 1 => Ok(Input::Private(*value)),
+                _ => arithmetic_chip
+                    .load_private(layouter.namespace(|| "load variable"), *value)
+                    .map(Input::from),
+            })
+            .collect::<Result<Vec<Input<F>>, Error>>()?;
+
+        // compile the expression
+        let c = match self.compile(
+            &arithmetic_chip,
+            &mut layouter,
+            &self.expression,
+            &variables,
+        )? {
+            Input::Number(c) => c,
+            // a lone operand is not consumed by any instruction, so load it
+            Input::Private(c) => {
+                arithmetic_chip.load_private(layouter.namespace(|| "load c"), c)?
+            }
+            Input::Constant(c) => {
+                arithmetic_chip.load_constant(layouter.namespace(|| "load c"), c)?
+            }
+        };
 
         arithmetic_chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
     }
 }
 
+/// Calculator circuit compiler.
+impl<F: FieldExt> CalculatorCircuit<F> {
+    /// Compiles an expression into chip instructions and returns its result.
+    /// `a * b + c` and `c + a * b` are fused into a single multiply-add region.
+    fn compile(
+        &self,
+        arithmetic_chip: &ArithmeticChip<F>,
+        layouter: &mut impl Layouter<F>,
+        expression: &Expression,
+        variables: &[Input<F>],
+    ) -> Result<Input<F>, Error> {
+        match expression {
+            Expression::Constant(constant) => Ok(Input::Constant(F::from(*constant))),
+            Expression::Element(value) => Ok(Input::Constant(field::from_biguint(value))),
+            Expression::Variable(index) => Ok(variables[*index].clone()),
+            Expression::Binary(operator, lhs, rhs) => {
+                // fuse a product with an addend
+                if let Operator::Add = operator {
+                    if let (Expression::Binary(Operator::Mul, a, b), c)
+                    | (c, Expression::Binary(Operator::Mul, a, b)) = (lhs.as_ref(), rhs.as_ref())
+                    {
+                        let a = self.compile(arithmetic_chip, layouter, a, variables)?;
+                        let b = self.compile(arithmetic_chip, layouter, b, variables)?;
+                        let c = self.compile(arithmetic_chip, layouter, c, variables)?;
+                        return arithmetic_chip.fma(layouter, a, b, c).map(Input::from);
+                    }
+                }
+
+                // apply a constant to a single non-constant operand through the
+                // gate coefficients, so the constant needs no advice cell
+                match (operator, lhs.as_ref(), rhs.as_ref()) {
+                    (Operator::Add, constant, e) | (Operator::Add, e, constant)
+                        if constant.is_constant() && !e.is_constant() =>
+                    {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip
+                            .add_constant(layouter, a, constant.evaluate(&[]))
+                            .map(Input::from);
+                    }
+                    (Operator::Sub, e, constant) if constant.is_constant() && !e.is_constant() => {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip
+                            .add_constant(layouter, a, -constant.evaluate::<F>(&[]))
+                            .map(Input::from);
+                    }
+                    (Operator::Mul, constant, e) | (Operator::Mul, e, constant)
+                        if constant.is_constant() && !e.is_constant() =>
+                    {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip
+                            .mul_constant(layouter, a, constant.evaluate(&[]))
+                            .map(Input::from);
+                    }
+                    // raise to a public exponent
+                    (Operator::Pow, e, Expression::Constant(exponent)) => {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip.pow(layouter, a, *exponent).map(Input::from);
+                    }
+                    // reduce under a public modulus
+                    (Operator::Mod, e, Expression::Constant(m)) => {
+                        return self.compile_mod(arithmetic_chip, layouter, e, variables, *m);
+                    }
+                    // shift by a public amount
+                    (Operator::Shl, e, Expression::Constant(n)) => {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip
+                            .shl(layouter, a, *n as usize)
+                            .map(Input::from);
+                    }
+                    (Operator::Shr, e, Expression::Constant(n)) => {
+                        let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                        return arithmetic_chip
+                            .shr(layouter, a, *n as usize)
+                            .map(Input::from);
+                    }
+                    _ => (),
+                }
+
+                let a = self.compile(arithmetic_chip, layouter, lhs, variables)?;
+                let b = self.compile(arithmetic_chip, layouter, rhs, variables)?;
+
+                match operator {
+                    Operator::Add => arithmetic_chip.add(layouter, a, b),
+                    Operator::Sub => arithmetic_chip.sub(layouter, a, b),
+                    Operator::Mul => arithmetic_chip.mul(layouter, a, b),
+                    Operator::Div => arithmetic_chip.div(layouter, a, b),
+                    // raise to a private exponent
+                    Operator::Pow => {
+                        arithmetic_chip.pow_private(layouter, a, b, self.exponent_bits)
+                    }
+                    Operator::Lt => arithmetic_chip.lt(layouter, a, b),
+                    Operator::Le => arithmetic_chip.le(layouter, a, b),
+                    // `a > b` is `b < a` and `a >= b` is `b <= a`
+                    Operator::Gt => arithmetic_chip.lt(layouter, b, a),
+                    Operator::Ge => arithmetic_chip.le(layouter, b, a),
+                    Operator::Eq => arithmetic_chip.eq(layouter, a, b),
+                    Operator::Ne => arithmetic_chip.ne(layouter, a, b),
+                    Operator::And => arithmetic_chip.and(layouter, a, b),
+                    Operator::Or => arithmetic_chip.or(layouter, a, b),
+                    Operator::BitAnd => arithmetic_chip.bit_and(layouter, a, b),
+                    Operator::BitOr => arithmetic_chip.bit_or(layouter, a, b),
+                    Operator::BitXor => arithmetic_chip.bit_xor(layouter, a, b),
+                    Operator::WrappingAdd => arithmetic_chip.wrapping_add(layouter, a, b),
+                    Operator::WrappingSub => arithmetic_chip.wrapping_sub(layouter, a, b),
+                    Operator::WrappingMul => arithmetic_chip.wrapping_mul(layouter, a, b),
+                    Operator::SaturatingAdd => arithmetic_chip.saturating_add(layouter, a, b),
+                    Operator::SaturatingSub => arithmetic_chip.saturating_sub(layouter, a, b),
+                    Operator::SaturatingMul => arithmetic_chip.saturating_mul(layouter, a, b),
+                    // the parser folds shifts and moduli into constants
+                    Operator::Shl | Operator::Shr | Operator::Mod => Err(Error::Synthesis),
+                }
+                .map(Input::from)
+            }
+            Expression::Not(e) => {
+                let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                arithmetic_chip.not(layouter, a).map(Input::from)
+            }
+            Expression::Select(condition, lhs, rhs) => {
+                let condition = self.compile(arithmetic_chip, layouter, condition, variables)?;
+                let a = self.compile(arithmetic_chip, layouter, lhs, variables)?;
+                let b = self.compile(arithmetic_chip, layouter, rhs, variables)?;
+                arithmetic_chip
+                    .select(layouter, condition, a, b)
+                    .map(Input::from)
+            }
+            Expression::FixedMul(lhs, rhs, format) => {
+                let a = self.compile(arithmetic_chip, layouter, lhs, variables)?;
+                let b = self.compile(arithmetic_chip, layouter, rhs, variables)?;
+                arithmetic_chip
+                    .fixed_mul(layouter, a, b, *format)
+                    .map(Input::from)
+            }
+            Expression::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.compile(arithmetic_chip, layouter, arg, variables))
+                    .collect::<Result<Vec<Input<F>>, Error>>()?;
+
+                match function {
+                    Function::Sqrt => arithmetic_chip.sqrt(layouter, args[0].clone()),
+                    Function::Abs => arithmetic_chip.abs(layouter, args[0].clone()),
+                    Function::Min => arithmetic_chip.min(layouter, &args),
+                    Function::Max => arithmetic_chip.max(layouter, &args),
+                    Function::Gcd => {
+                        arithmetic_chip.gcd(layouter, args[0].clone(), args[1].clone())
+                    }
+                    Function::Lcm => {
+                        arithmetic_chip.lcm(layouter, args[0].clone(), args[1].clone())
+                    }
+                }
+                .map(Input::from)
+            }
+        }
+    }
+
+    /// Compiles the left hand side of a `mod m` into modular instructions
+    /// and returns its reduced result. Additions, subtractions,
+    /// multiplications and public powers are reduced after every step, any
+    /// other subexpression is compiled as usual and then reduced.
+    fn compile_mod(
+        &self,
+        arithmetic_chip: &ArithmeticChip<F>,
+        layouter: &mut impl Layouter<F>,
+        expression: &Expression,
+        variables: &[Input<F>],
+        modulus: Operand,
+    ) -> Result<Input<F>, Error> {
+        match expression {
+            // constants are reduced natively, they are public anyway
+            Expression::Constant(constant) => Ok(Input::Constant(F::from(constant % modulus))),
+            Expression::Element(value) => Ok(Input::Constant(F::from(
+                value.clone().div_rem_small(modulus),
+            ))),
+            Expression::Binary(
+                operator @ (Operator::Add | Operator::Sub | Operator::Mul),
+                lhs,
+                rhs,
+            ) => {
+                let a = self.compile_mod(arithmetic_chip, layouter, lhs, variables, modulus)?;
+                let b = self.compile_mod(arithmetic_chip, layouter, rhs, variables, modulus)?;
+
+                match operator {
+                    Operator::Add => arithmetic_chip.add_mod(layouter, a, b, modulus),
+                    Operator::Sub => arithmetic_chip.sub_mod(layouter, a, b, modulus),
+                    _ => arithmetic_chip.mul_mod(layouter, a, b, modulus),
+                }
+                .map(Input::from)
+            }
+            // the parser only accepts public exponents under a modulus
+            Expression::Binary(Operator::Pow, base, exponent) => {
+                let exponent = exponent.constant_value().ok_or(Error::Synthesis)?;
+                let a = self.compile_mod(arithmetic_chip, layouter, base, variables, modulus)?;
+                arithmetic_chip
+                    .pow_mod(layouter, a, exponent, modulus)
+                    .map(Input::from)
+            }
+            e => {
+                let a = self.compile(arithmetic_chip, layouter, e, variables)?;
+                arithmetic_chip
+                    .reduce_mod(layouter, a, modulus)
+                    .map(Input::from)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use halo2_proofs::{dev::MockProver, pasta::Fp};
+    use crate::{
+        expression::{parse, parse_field, parse_fixed, parse_integer},
+        fixed::{FixedPoint, Rounding},
+    };
+    use halo2_proofs::{
+        arithmetic::Field,
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{
+            Advice, Any, Assigned, Assignment, Column, Fixed, FloorPlanner, Instance, Selector,
+        },
+    };
+
+    /// Records the rows and copy constraints used by a circuit's layout.
+    #[derive(Default)]
+    struct RowCounter {
+        /// Number of rows used.
+        rows: usize,
+        /// Number of copy constraints.
+        copies: usize,
+    }
+
+    impl RowCounter {
+        /// Lays out the circuit and returns its row and copy counts.
+        fn measure(circuit: &CalculatorCircuit<Fp>) -> (usize, usize) {
+            let mut meta = ConstraintSystem::<Fp>::default();
+            let config = CalculatorCircuit::configure(&mut meta);
+
+            let constants = vec![config.constant()];
+
+            let mut counter = RowCounter::default();
+            SimpleFloorPlanner::synthesize(&mut counter, circuit, config, constants).unwrap();
+
+            (counter.rows, counter.copies)
+        }
+    }
+
+    impl Assignment<Fp> for RowCounter {
+        fn enter_region<NR, N>(&mut self, _: N)
+        where
+            NR: Into<String>,
+            N: FnOnce() -> NR,
+        {
+        }
+
+        fn exit_region(&mut self) {}
+
+        fn enable_selector<A, AR>(&mut self, _: A, _: &Selector, row: usize) -> Result<(), Error>
+        where
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+        {
+            self.rows = self.rows.max(row + 1);
+            Ok(())
+        }
+
+        fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<Fp>, Error> {
+            Ok(Value::unknown())
+        }
+
+        fn assign_advice<V, VR, A, AR>(
+            &mut self,
+            _: A,
+            _: Column<Advice>,
+            row: usize,
+            _: V,
+        ) -> Result<(), Error>
+        where
+            V: FnOnce() -> Value<VR>,
+            VR: Into<Assigned<Fp>>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+        {
+            self.rows = self.rows.max(row + 1);
+            Ok(())
+        }
+
+        fn assign_fixed<V, VR, A, AR>(
+            &mut self,
+            _: A,
+            _: Column<Fixed>,
+            row: usize,
+            _: V,
+        ) -> Result<(), Error>
+        where
+            V: FnOnce() -> Value<VR>,
+            VR: Into<Assigned<Fp>>,
+            A: FnOnce() -> AR,
+            AR: Into<String>,
+        {
+            self.rows = self.rows.max(row + 1);
+            Ok(())
+        }
+
+        fn copy(
+            &mut self,
+            _: Column<Any>,
+            _: usize,
+            _: Column<Any>,
+            _: usize,
+        ) -> Result<(), Error> {
+            self.copies += 1;
+            Ok(())
+        }
+
+        fn fill_from_row(
+            &mut self,
+            _: Column<Fixed>,
+            _: usize,
+            _: Value<Assigned<Fp>>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn push_namespace<NR, N>(&mut self, _: N)
+        where
+            NR: Into<String>,
+            N: FnOnce() -> NR,
+        {
+        }
+
+        fn pop_namespace(&mut self, _: Option<String>) {}
+    }
+
+    fn build(input: &str, values: &[u64]) -> CalculatorCircuit<Fp> {
+        let (expression, variables) = parse(input).unwrap();
+        assert_eq!(variables.len(), values.len());
+
+        CalculatorCircuit {
+            expression,
+            variables: values.iter().map(|v| Value::known(Fp::from(*v))).collect(),
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        }
+    }
+
+    fn binary(operator: Operator) -> Expression {
+        Expression::Binary(
+            operator,
+            Box::new(Expression::Variable(0)),
+            Box::new(Expression::Variable(1)),
+        )
+    }
 
     #[test]
     fn test_add() {
@@ -82,9 +488,9 @@ mod tests {
         let c = a + b;
 
         let circuit = CalculatorCircuit {
-            operator: Operator::Add,
-            a: Value::known(a),
-            b: Value::known(b),
+            expression: binary(Operator::Add),
+            variables: vec![Value::known(a), Value::known(b)],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
         };
 
         let mut public_inputs = vec![c];
@@ -107,9 +513,9 @@ mod tests {
         let c = a * b;
 
         let circuit = CalculatorCircuit {
-            operator: Operator::Mul,
-            a: Value::known(a),
-            b: Value::known(b),
+            expression: binary(Operator::Mul),
+            variables: vec![Value::known(a), Value::known(b)],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
         };
 
         let mut public_inputs = vec![c];
@@ -132,9 +538,9 @@ mod tests {
         let c = a - b;
 
         let circuit = CalculatorCircuit {
-            operator: Operator::Sub,
-            a: Value::known(a),
-            b: Value::known(b),
+            expression: binary(Operator::Sub),
+            variables: vec![Value::known(a), Value::known(b)],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
         };
 
         let mut public_inputs = vec![c];
@@ -147,4 +553,712 @@ mod tests {
         let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
         assert!(prover.verify().is_err());
     }
+
+    #[test]
+    fn test_fma() {
+        let k = 4;
+
+        for (input, values) in [("?a * ?b + ?c", [2, 3, 4]), ("?a + ?b * ?c", [4, 2, 3])] {
+            let circuit = build(input, &values);
+
+            let mut public_inputs = vec![Fp::from(10)];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            public_inputs[0] += Fp::one();
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_expression() {
+        let k = 5;
+
+        let circuit = build("(?a - 2) * (3 + ?b) + 6 * ?a * 5", &[7, 1]);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(230)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_constants() {
+        let k = 4;
+
+        // a constant-only expression has no witnesses at all
+        let circuit = build("5 * 3", &[]);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(15)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(16)]]).unwrap();
+        assert!(prover.verify().is_err());
+
+        // a lone constant is loaded from the constant column
+        let circuit = build("5", &[]);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(5)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // integer literals past u64 are field element constants
+        let (expression, _) =
+            parse_integer::<Fp>("?a * 340282366920938463463374607431768211456").unwrap();
+        let circuit = CalculatorCircuit {
+            expression,
+            ..build("?a", &[3])
+        };
+        let output = Fp::from(3) * Fp::from_u128(1 << 64) * Fp::from_u128(1 << 64);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![output]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_affine() {
+        let k = 4;
+
+        for (input, output) in [
+            ("?a * 5", Fp::from(35)),
+            ("3 + ?a", Fp::from(10)),
+            ("(?a - 32) * 5", -Fp::from(125)),
+        ] {
+            let circuit = build(input, &[7]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![output]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            let prover = MockProver::run(k, &circuit, vec![vec![output + Fp::one()]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        let k = 7;
+
+        let a = Fp::from(3);
+
+        for exponent in [0, 1, 2, 13, 1 << 40, u64::MAX] {
+            let circuit = build(&format!("?a ^ {}", exponent), &[3]);
+
+            let mut public_inputs = vec![a.pow(&[exponent, 0, 0, 0])];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            public_inputs[0] += Fp::one();
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        // right associative, `2 ^ (3 ^ 2)`
+        let circuit = build("?a ^ 3 ^ 2", &[2]);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(512)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_pow_private() {
+        let k = 8;
+
+        for (exponent, bits) in [(0, 1), (1, 1), (13, 4), (255, 8), (u64::MAX, 64)] {
+            let mut circuit = build("3 ^ ?e", &[exponent]);
+            circuit.exponent_bits = bits;
+
+            let mut public_inputs = vec![Fp::from(3).pow(&[exponent, 0, 0, 0])];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            public_inputs[0] += Fp::one();
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        // an exponent wider than the configured bit width can not be proven
+        let mut circuit = build("3 ^ ?e", &[256]);
+        circuit.exponent_bits = 8;
+
+        let public_inputs = vec![Fp::from(3).pow(&[256, 0, 0, 0])];
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+
+        // the exponent may be an intermediate result
+        let mut circuit = build("?a ^ (?e + 1)", &[2, 4]);
+        circuit.exponent_bits = 8;
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(32)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_compare() {
+        let k = 8;
+
+        for (a, b) in [(3, 5), (5, 3), (4, 4), (0, u64::MAX), (u64::MAX, 0)] {
+            for (operator, output) in [
+                ("<", a < b),
+                ("<=", a <= b),
+                (">", a > b),
+                (">=", a >= b),
+                ("==", a == b),
+                ("!=", a != b),
+            ] {
+                let circuit = build(&format!("?a {} ?b", operator), &[a, b]);
+
+                let output = Fp::from(output as u64);
+
+                let prover = MockProver::run(k, &circuit, vec![vec![output]]).unwrap();
+                assert_eq!(prover.verify(), Ok(()));
+
+                let prover = MockProver::run(k, &circuit, vec![vec![Fp::one() - output]]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // comparisons against constants and of intermediate results
+        let circuit = build("?a * 2 >= 1000", &[500]);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::one()]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // an operand outside of u64 can not be ordered, whatever the output
+        let circuit = CalculatorCircuit {
+            expression: binary(Operator::Lt),
+            variables: vec![Value::known(-Fp::one()), Value::known(Fp::from(5))],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        };
+
+        for output in [Fp::zero(), Fp::one()] {
+            let prover = MockProver::run(k, &circuit, vec![vec![output]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_logic() {
+        let k = 4;
+
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            for (input, output) in [
+                ("?a && ?b", a & b),
+                ("?a || ?b", a | b),
+                ("!?a", 1 - a),
+                ("?a ? ?b : 7", if a == 1 { b } else { 7 }),
+            ] {
+                let (expression, variables) = parse(input).unwrap();
+                let circuit = CalculatorCircuit {
+                    expression,
+                    variables: [a, b][..variables.len()]
+                        .iter()
+                        .map(|v| Value::known(Fp::from(*v)))
+                        .collect(),
+                    exponent_bits: DEFAULT_EXPONENT_BITS,
+                };
+
+                let mut public_inputs = vec![Fp::from(output)];
+
+                let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+                assert_eq!(prover.verify(), Ok(()));
+
+                public_inputs[0] += Fp::one();
+
+                let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // non-boolean operands and conditions are rejected
+        for (input, values, output) in [
+            ("?a && ?b", [2, 1], 2),
+            ("?a || ?b", [2, 0], 2),
+            ("?a ? ?b : 0", [2, 3], 6),
+        ] {
+            let circuit = build(input, &values);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let k = 17;
+
+        // one circuit covers all three operators, the table has 2^16 rows
+        let (a, b) = (0xdead_beef_0123_4567_u64, 0x0f0f_f0f0_ffff_0000_u64);
+        let circuit = build("(?a & ?b) xor ?a | ?b", &[a, b]);
+        let output = ((a & b) ^ a) | b;
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(output ^ 1)]]).unwrap();
+        assert!(prover.verify().is_err());
+
+        // operands outside of u64 have no byte decomposition
+        let circuit = CalculatorCircuit {
+            variables: vec![Value::known(-Fp::one()), Value::known(Fp::from(u64::MAX))],
+            ..build("?a & ?b", &[0, 0])
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(u64::MAX)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_shift() {
+        let k = 9;
+
+        let a = 0xdead_beef_0123_4567_u64;
+        for n in [0, 1, 13, 63] {
+            for (input, output) in [
+                (format!("?a << {}", n), a << n),
+                (format!("?a >> {}", n), a >> n),
+            ] {
+                let circuit = build(&input, &[a]);
+
+                let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+                assert_eq!(prover.verify(), Ok(()));
+
+                let prover =
+                    MockProver::run(k, &circuit, vec![vec![Fp::from(output ^ 1)]]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // the shifted operand must be a u64
+        let circuit = CalculatorCircuit {
+            variables: vec![Value::known(Fp::from_u128(1 << 64))],
+            ..build("?a >> 1", &[0])
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(1 << 63)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let k = 9;
+
+        // edge cases around the carries, and a large product
+        let values = [
+            0,
+            1,
+            u32::MAX as u64,
+            1 << 63,
+            u64::MAX,
+            0x9e37_79b9_7f4a_7c15,
+        ];
+
+        // differential test against Rust's u64 semantics
+        for a in values {
+            for b in values {
+                for (input, output) in [
+                    ("?a +% ?b", a.wrapping_add(b)),
+                    ("?a -% ?b", a.wrapping_sub(b)),
+                    ("?a *% ?b", a.wrapping_mul(b)),
+                ] {
+                    let circuit = build(input, &[a, b]);
+                    assert_eq!(
+                        circuit.expression.evaluate(&[Fp::from(a), Fp::from(b)]),
+                        Fp::from(output)
+                    );
+
+                    let prover =
+                        MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+                    assert_eq!(prover.verify(), Ok(()));
+                }
+            }
+        }
+
+        // the field result is not accepted in place of the wrapped one
+        for (input, values, output) in [
+            ("?a +% ?b", [u64::MAX, 1], Fp::from_u128(1 << 64)),
+            ("?a -% ?b", [0, 1], -Fp::one()),
+            ("?a *% ?b", [1 << 32, 1 << 32], Fp::from_u128(1 << 64)),
+        ] {
+            let circuit = build(input, &values);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![output]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        // operands outside of u64 are rejected
+        let circuit = CalculatorCircuit {
+            variables: vec![
+                Value::known(Fp::from_u128(1 << 64)),
+                Value::known(Fp::one()),
+            ],
+            ..build("?a +% ?b", &[0, 0])
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::one()]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_saturating() {
+        let k = 9;
+
+        let values = [0, 1, u32::MAX as u64, 1 << 32, u64::MAX - 1, u64::MAX];
+
+        // differential test against Rust's u64 semantics
+        for a in values {
+            for b in values {
+                for (input, output) in [
+                    ("?a sat+ ?b", a.saturating_add(b)),
+                    ("?a sat- ?b", a.saturating_sub(b)),
+                    ("?a sat* ?b", a.saturating_mul(b)),
+                ] {
+                    let circuit = build(input, &[a, b]);
+
+                    let prover =
+                        MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+                    assert_eq!(prover.verify(), Ok(()));
+                }
+            }
+        }
+
+        // neither the wrapped result nor the bound is accepted in place of
+        // the other
+        for (input, values, output) in [
+            ("?a sat+ ?b", [u64::MAX, 2], 1),
+            ("?a sat- ?b", [1, 2], u64::MAX),
+            ("?a sat* ?b", [1 << 32, 1 << 32], 0),
+            ("?a sat+ ?b", [1, 2], u64::MAX),
+            ("?a sat- ?b", [2, 1], 0),
+            ("?a sat* ?b", [1 << 31, 2], u64::MAX),
+        ] {
+            let circuit = build(input, &values);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(output)]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_div() {
+        let k = 5;
+
+        // a field literal past u64 divided by a private element
+        let (expression, _) = parse_field::<Fp>("0x10000000000000001 / ?a").unwrap();
+        let a = -Fp::from(3);
+        let c = Fp::from_u128((1 << 64) + 1) * a.invert().unwrap();
+
+        let circuit = CalculatorCircuit {
+            expression,
+            variables: vec![Value::known(a)],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![c]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // division by zero has no inverse to witness
+        let circuit = CalculatorCircuit {
+            variables: vec![Value::known(Fp::zero())],
+            ..circuit
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::zero()]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_fixed_mul() {
+        let k = 10;
+
+        // 12.50 · 1.08 = 13.50, then 13.50 · 0.07 = 0.945 rounds by the mode
+        for (rounding, c) in [
+            (Rounding::Floor, 94),
+            (Rounding::Ceil, 95),
+            (Rounding::HalfUp, 95),
+        ] {
+            let format = FixedPoint::new(2, rounding).unwrap();
+            let (expression, _) = parse_fixed("?a * 1.08 * 0.07", format).unwrap();
+
+            let circuit = CalculatorCircuit {
+                expression,
+                variables: vec![Value::known(Fp::from(1250))],
+                exponent_bits: DEFAULT_EXPONENT_BITS,
+            };
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(c)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // the result of another rounding mode is not accepted
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(c + 1)]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        // a product past u64 fails the quotient range check
+        let format = FixedPoint::new(2, Rounding::HalfUp).unwrap();
+        let (expression, _) = parse_fixed("?a * ?a", format).unwrap();
+        let circuit = CalculatorCircuit {
+            expression,
+            variables: vec![Value::known(Fp::from(u64::MAX))],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        };
+        let c = Fp::from_u128(format.mul(u64::MAX, u64::MAX));
+
+        let prover = MockProver::run(k, &circuit, vec![vec![c]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let k = 9;
+
+        // perfect squares and the values just below and above them
+        let max_root = u32::MAX as u64;
+        for (a, s) in [
+            (0, 0),
+            (1, 1),
+            (15, 3),
+            (16, 4),
+            (17, 4),
+            (max_root * max_root - 1, max_root - 1),
+            (max_root * max_root, max_root),
+            (max_root * max_root + 1, max_root),
+            (u64::MAX, max_root),
+        ] {
+            let circuit = build("sqrt(?a)", &[a]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(s)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // neither neighbor of the root is accepted
+            for wrong in [Fp::from(s) - Fp::one(), Fp::from(s) + Fp::one()] {
+                let prover = MockProver::run(k, &circuit, vec![vec![wrong]]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // the argument is any expression
+        let circuit = build("sqrt(?a * ?a + 2 * ?a) + 1", &[9]);
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(10)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_gcd() {
+        let k = 10;
+
+        // zero inputs, coprime inputs, equal inputs and the largest u64
+        for (a, b, g, l) in [
+            (12, 18, 6, 36),
+            (18, 12, 6, 36),
+            (7, 13, 1, 91),
+            (9, 9, 9, 9),
+            (0, 5, 5, 0),
+            (5, 0, 5, 0),
+            (0, 0, 0, 0),
+            (
+                u64::MAX,
+                u64::MAX - 1,
+                1,
+                u64::MAX as u128 * (u64::MAX - 1) as u128,
+            ),
+            (u64::MAX, 5, 5, u64::MAX as u128),
+        ] {
+            let circuit = build("gcd(?a, ?b)", &[a, b]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(g)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // a common divisor that is not the greatest is not accepted
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::one()]]).unwrap();
+            assert_eq!(prover.verify().is_ok(), g == 1);
+
+            // a least common multiple past u64 fails its range check
+            let circuit = build("lcm(?a, ?b)", &[a, b]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from_u128(l)]]).unwrap();
+            assert_eq!(prover.verify().is_ok(), l <= u64::MAX as u128);
+        }
+    }
+
+    #[test]
+    fn test_abs() {
+        let k = 9;
+
+        let max = u64::MAX;
+        for (input, values, c) in [
+            ("abs(?a - ?b)", [3, 5], 2),
+            ("abs(?a - ?b)", [5, 3], 2),
+            ("abs(?a - ?b)", [7, 7], 0),
+            ("abs(?a - ?b)", [max, 0], max),
+            ("abs(?a - ?b)", [0, max], max),
+            ("abs(0 - ?a) + ?b", [4, 1], 5),
+        ] {
+            let circuit = build(input, &values);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(c)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // the magnitude under the other sign is not accepted
+            if c != 0 {
+                let prover = MockProver::run(k, &circuit, vec![vec![-Fp::from(c)]]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // a magnitude past u64 is rejected
+        let circuit = build("abs(?a * ?b)", &[max, max]);
+        let c = circuit.expression.evaluate(&[Fp::from(max), Fp::from(max)]);
+        let prover = MockProver::run(k, &circuit, vec![vec![c]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let k = 10;
+
+        let max = u64::MAX;
+        for values in [[3, 1, 2], [1, 2, 3], [3, 2, 1], [7, 7, 7], [0, max, 5]] {
+            let smallest = *values.iter().min().unwrap();
+            let largest = *values.iter().max().unwrap();
+
+            for (input, c) in [("min(?a, ?b, ?c)", smallest), ("max(?a, ?b, ?c)", largest)] {
+                let circuit = build(input, &values);
+
+                let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(c)]]).unwrap();
+                assert_eq!(prover.verify(), Ok(()));
+
+                // any other input is not accepted
+                for other in values.iter().filter(|v| **v != c) {
+                    let prover =
+                        MockProver::run(k, &circuit, vec![vec![Fp::from(*other)]]).unwrap();
+                    assert!(prover.verify().is_err());
+                }
+            }
+        }
+
+        // a cap only reveals whether it was hit, not the capped value
+        for (x, capped) in [(400, 0), (500, 1), (900, 1)] {
+            let circuit = build("min(?x, 500) == 500", &[x]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(capped)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_mod() {
+        let k = 11;
+
+        let max = u64::MAX;
+        for (input, values) in [
+            ("?a * ?b mod 7", [5, 6]),
+            ("?a - ?b mod 7", [2, 5]),
+            ("?a ^ 13 + ?b mod 1000000007", [123456789, 987654321]),
+            ("?a * ?b - 1 mod 18446744073709551615", [max - 1, max - 2]),
+            ("?a * ?b mod 1", [5, 6]),
+            ("(?a mod 10) * ?b mod 7", [99, 3]),
+            ("?a ^ 0 + ?b mod 7", [5, 6]),
+        ] {
+            let circuit = build(input, &values);
+            let values = values.map(Fp::from);
+            let c = circuit.expression.evaluate(&values);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![c]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // the unreduced result is not accepted
+            let unreduced = parse(input.rsplit_once(" mod ").unwrap().0).unwrap().0;
+            let unreduced = unreduced.evaluate(&values);
+            if unreduced != c {
+                let prover = MockProver::run(k, &circuit, vec![vec![unreduced]]).unwrap();
+                assert!(prover.verify().is_err());
+            }
+        }
+
+        // `a - b` wraps under the modulus, not the field
+        let circuit = build("?a - ?b mod 7", &[2, 5]);
+        assert_eq!(
+            circuit.expression.evaluate(&[Fp::from(2), Fp::from(5)]),
+            Fp::from(4)
+        );
+    }
+
+    #[test]
+    fn test_tax_brackets() {
+        let k = 10;
+
+        // no tax up to 10000, 20% up to 50000 and 40% above, in cents
+        let input =
+            "?i <= 10000 ? 0 : ?i <= 50000 ? (?i - 10000) * 20 : 800000 + (?i - 50000) * 40";
+
+        for (income, tax) in [(5000, 0), (30000, 400000), (70000, 1600000)] {
+            let circuit = build(input, &[income]);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(tax)]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(tax + 1)]]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+    }
+
+    #[test]
+    fn test_row_count() {
+        let circuit = build("(?a + ?b) - ?c * ?d", &[1, 2, 3, 4]);
+
+        // three two-row instructions and no load regions. loading each of the
+        // four operands into its own region used to cost four more rows and
+        // four more copy constraints.
+        // the copies are the two intermediate results and the public output.
+        assert_eq!(RowCounter::measure(&circuit), (6, 3));
+
+        // a reused variable is loaded once and copied into both instructions
+        let circuit = build("?a * ?a + ?b", &[1, 2]);
+        assert_eq!(RowCounter::measure(&circuit), (3, 3));
+
+        // constants applied to a single operand live in the gate
+        // coefficients, so only the output is copied
+        let circuit = build("5 * ?a", &[1]);
+        assert_eq!(RowCounter::measure(&circuit), (2, 1));
+
+        let circuit = build("(?a - 32) * 5", &[1]);
+        assert_eq!(RowCounter::measure(&circuit), (4, 2));
+
+        // a field literal past u64 or a negative one is a single constant
+        // as well
+        for input in ["?a * 0x10000000000000001", "?a + -1"] {
+            let (expression, _) = parse_field::<Fp>(input).unwrap();
+            let circuit = CalculatorCircuit {
+                expression,
+                ..build("?a", &[1])
+            };
+            assert_eq!(RowCounter::measure(&circuit), (2, 1));
+        }
+
+        // one row per bit of the exponent, the base is copied once
+        let circuit = build("?a ^ 13", &[1]);
+        assert_eq!(RowCounter::measure(&circuit), (4, 2));
+
+        let circuit = build("?a ^ 18446744073709551615", &[1]);
+        assert_eq!(RowCounter::measure(&circuit), (64, 2));
+
+        // two rows per bit of the exponent bit width plus two, whatever the
+        // exponent. the copies are the initial accumulator and running sum,
+        // the constant base and the public output.
+        for exponent in [0, 255] {
+            let mut circuit = build("3 ^ ?e", &[exponent]);
+            circuit.exponent_bits = 8;
+            assert_eq!(RowCounter::measure(&circuit), (18, 4));
+        }
+
+        // constants that are not applied to a single operand are copied
+        // from the constant column rather than witnessed
+        let circuit = build("5 - ?a", &[1]);
+        assert_eq!(RowCounter::measure(&circuit), (2, 2));
+    }
 }
@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter},
+    plonk::Error,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    chips::{
+        arithmetic::{Input, Number},
+        compare::BITS,
+        mul,
+        plonk::{PlonkChip, PlonkConfig, PlonkInstructions},
+        range::{RangeChip, RangeConfig, RangeInstructions},
+        select::{SelectChip, SelectConfig, SelectInstructions},
+    },
+    field,
+};
+
+/// Absolute value instruction set.
+pub trait AbsInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Absolute value instruction.
+    /// Takes a signed input, negative values being the field negation of
+    /// their magnitude, and returns its magnitude, which must fit into a u64.
+    fn abs(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error>;
+}
+
+/// Absolute value chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct AbsConfig {
+    /// PLONK chip configuration, for applying the sign.
+    plonk_config: PlonkConfig,
+    /// Conditional select chip configuration, for the sign of the sign bit.
+    select_config: SelectConfig,
+    /// Range check chip configuration, for the magnitude.
+    range_config: RangeConfig,
+}
+
+/// Absolute value chip definition.
+///
+/// The chip has no gate of its own. It witnesses a sign bit `s`, selects the
+/// sign `s ? -1 : 1` with the select gate, which constrains `s` to be
+/// boolean, multiplies the input by the sign and range checks the product to
+/// u64. Only one of `a` and `-a` fits into a u64 unless `a` is zero, so the
+/// sign bit cannot be chosen freely.
+pub struct AbsChip<F: FieldExt> {
+    /// Absolute value configuration.
+    config: AbsConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Absolute value chip implementation.
+impl<F: FieldExt> AbsChip<F> {
+    /// Construct AbsChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure AbsChip and return the Config.
+    pub fn configure(
+        plonk_config: PlonkConfig,
+        select_config: SelectConfig,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // the absolute value chip only composes the gates of other chips
+        AbsConfig {
+            plonk_config,
+            select_config,
+            range_config,
+        }
+    }
+}
+
+/// Halo2 Chip implementation for AbsChip.
+impl<F: FieldExt> Chip<F> for AbsChip<F> {
+    /// Absolute value configuration.
+    type Config = AbsConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Absolute value instruction set implementation for AbsChip.
+impl<F: FieldExt> AbsInstructions<F> for AbsChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Absolute value instruction implementation.
+    fn abs(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the composed chips
+        let plonk_chip = PlonkChip::<F>::construct(config.plonk_config.clone(), ());
+        let select_chip = SelectChip::<F>::construct(config.select_config.clone(), ());
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the sign bit, an input that is not a small signed integer
+        // fails the range check either way
+        let negative = a.value().map(|a| {
+            let negative = field::to_signed(&a).is_some_and(|a| a < 0);
+            F::from(negative as u64)
+        });
+
+        // select the sign, constraining the sign bit to be boolean
+        let sign = select_chip.select(
+            layouter,
+            Input::Private(negative),
+            Input::Constant(-F::one()),
+            Input::Constant(F::one()),
+        )?;
+
+        // apply the sign, the magnitude is a u64 only under the right sign
+        let magnitude = plonk_chip.apply(layouter, a, sign.into(), mul::coefficients())?;
+        range_chip.range_check(layouter, magnitude.into(), BITS)
+    }
+}
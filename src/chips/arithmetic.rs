@@ -2,19 +2,67 @@ use std::marker::PhantomData;
 
 use halo2_proofs::{
     arithmetic::FieldExt,
-    circuit::{AssignedCell, Chip, Layouter, Value},
-    plonk::{Advice, Column, ConstraintSystem, Error, Instance},
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Instance},
 };
 
-use crate::chips::{
-    add::{AddChip, AddConfig, AddInstructions},
-    mul::{MulChip, MulConfig, MulInstructions},
-    sub::{SubChip, SubConfig, SubInstructions},
+use crate::{
+    chips::{
+        abs::{AbsChip, AbsConfig, AbsInstructions},
+        add::{self, AddConstantInstructions, AddInstructions},
+        bigint::{BigIntChip, BigIntConfig, BigIntInstructions},
+        bitwise::{BitwiseChip, BitwiseConfig, BitwiseInstructions, ShiftInstructions},
+        compare::{CompareChip, CompareConfig, CompareInstructions},
+        div::{DivChip, DivConfig, DivInstructions},
+        fixed::{FixedPointChip, FixedPointConfig, FixedPointInstructions},
+        fma::{FmaChip, FmaConfig, FmaInstructions},
+        gcd::{GcdChip, GcdConfig, GcdInstructions},
+        logic::{LogicChip, LogicConfig, LogicInstructions},
+        min_max::{MinMaxChip, MinMaxConfig, MinMaxInstructions},
+        modular::{ModularChip, ModularConfig, ModularInstructions},
+        mul::{self, MulConstantInstructions, MulInstructions},
+        plonk::{PlonkChip, PlonkConfig, PlonkInstructions},
+        pow::{PowChip, PowConfig, PowInstructions},
+        private_pow::{PrivatePowChip, PrivatePowConfig, PrivatePowInstructions},
+        range::{RangeChip, RangeConfig, RangeInstructions},
+        rational::{Fraction, RationalChip, RationalConfig, RationalInstructions},
+        saturating::{SaturatingChip, SaturatingConfig, SaturatingInstructions},
+        select::{SelectChip, SelectConfig, SelectInstructions},
+        sqrt::{SqrtChip, SqrtConfig, SqrtInstructions},
+        sub::{self, SubInstructions},
+        wrapping::{WrappingChip, WrappingConfig, WrappingInstructions},
+    },
+    fixed::FixedPoint,
+    zk_calculator::Operand,
 };
 
 /// Top-level arithmetic instruction set.
 pub trait ArithmeticInstructions<F: FieldExt>:
-    AddInstructions<F> + MulInstructions<F> + SubInstructions<F>
+    AbsInstructions<F>
+    + AddInstructions<F>
+    + AddConstantInstructions<F>
+    + BigIntInstructions<F>
+    + BitwiseInstructions<F>
+    + CompareInstructions<F>
+    + DivInstructions<F>
+    + FixedPointInstructions<F>
+    + FmaInstructions<F>
+    + GcdInstructions<F>
+    + LogicInstructions<F>
+    + MinMaxInstructions<F>
+    + ModularInstructions<F>
+    + MulInstructions<F>
+    + MulConstantInstructions<F>
+    + PowInstructions<F>
+    + PrivatePowInstructions<F>
+    + RangeInstructions<F>
+    + RationalInstructions<F>
+    + SaturatingInstructions<F>
+    + SelectInstructions<F>
+    + ShiftInstructions<F>
+    + SqrtInstructions<F>
+    + SubInstructions<F>
+    + WrappingInstructions<F>
 {
     /// Numeric variable.
     type Num;
@@ -26,6 +74,13 @@ pub trait ArithmeticInstructions<F: FieldExt>:
         value: Value<F>,
     ) -> Result<<Self as ArithmeticInstructions<F>>::Num, Error>;
 
+    /// Loads a constant number into the circuit.
+    fn load_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<<Self as ArithmeticInstructions<F>>::Num, Error>;
+
     /// Exposes a number as a public input to the circuit.
     fn expose_public(
         &self,
@@ -39,6 +94,56 @@ pub trait ArithmeticInstructions<F: FieldExt>:
 #[derive(Clone)]
 pub struct Number<F: FieldExt>(pub AssignedCell<F, F>);
 
+/// Instruction input type. Imported into each chip's implementation.
+/// Fresh private values are witnessed directly into the instruction's region,
+/// numbers that were already assigned are copied in with a copy constraint and
+/// constants are copied from the fixed constant column.
+#[derive(Clone)]
+pub enum Input<F: FieldExt> {
+    /// Fresh private value.
+    Private(Value<F>),
+    /// Previously assigned number.
+    Number(Number<F>),
+    /// Constant baked into the circuit.
+    Constant(F),
+}
+
+/// Instruction input implementation.
+impl<F: FieldExt> Input<F> {
+    /// Returns the value of the input.
+    pub fn value(&self) -> Value<F> {
+        match self {
+            Input::Private(value) => *value,
+            Input::Number(number) => number.0.value().copied(),
+            Input::Constant(constant) => Value::known(*constant),
+        }
+    }
+
+    /// Assigns the input to a column and offset of the region.
+    pub fn assign(
+        &self,
+        annotation: &str,
+        region: &mut Region<'_, F>,
+        column: Column<Advice>,
+        offset: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        match self {
+            Input::Private(value) => region.assign_advice(|| annotation, column, offset, || *value),
+            Input::Number(number) => number.0.copy_advice(|| annotation, region, column, offset),
+            Input::Constant(constant) => {
+                region.assign_advice_from_constant(|| annotation, column, offset, *constant)
+            }
+        }
+    }
+}
+
+/// Converts an assigned number into an instruction input.
+impl<F: FieldExt> From<Number<F>> for Input<F> {
+    fn from(number: Number<F>) -> Self {
+        Input::Number(number)
+    }
+}
+
 /// Top-level arithmetic chip configuration.
 /// Derived during `Chip::configure`.
 #[derive(Clone, Debug)]
@@ -53,12 +158,59 @@ pub struct ArithmeticConfig {
     b: Column<Advice>,
     /// Instance column for public inputs.
     instance: Column<Instance>,
-    /// Addition chip configuration.
-    add_config: AddConfig,
-    /// Subtraction chip configuration.
-    sub_config: SubConfig,
-    /// Multiplication chip configuration.
-    mul_config: MulConfig,
+    /// Fixed column for constants.
+    constant: Column<Fixed>,
+    /// PLONK chip configuration, shared by addition, subtraction and
+    /// multiplication.
+    plonk_config: PlonkConfig,
+    /// Fused multiply-add chip configuration.
+    fma_config: FmaConfig,
+    /// Exponentiation chip configuration.
+    pow_config: PowConfig,
+    /// Private exponentiation chip configuration.
+    private_pow_config: PrivatePowConfig,
+    /// Range check chip configuration.
+    range_config: RangeConfig,
+    /// Comparison chip configuration.
+    compare_config: CompareConfig,
+    /// Boolean logic chip configuration.
+    logic_config: LogicConfig,
+    /// Conditional select chip configuration.
+    select_config: SelectConfig,
+    /// Bitwise chip configuration, shared by bitwise operators and shifts.
+    bitwise_config: BitwiseConfig,
+    /// Wrapping arithmetic chip configuration.
+    wrapping_config: WrappingConfig,
+    /// Saturating arithmetic chip configuration.
+    saturating_config: SaturatingConfig,
+    /// Big integer chip configuration.
+    bigint_config: BigIntConfig,
+    /// Field division chip configuration.
+    div_config: DivConfig,
+    /// Fixed-point chip configuration.
+    fixed_config: FixedPointConfig,
+    /// Rational chip configuration.
+    rational_config: RationalConfig,
+    /// Square root chip configuration.
+    sqrt_config: SqrtConfig,
+    /// Greatest common divisor chip configuration.
+    gcd_config: GcdConfig,
+    /// Modular arithmetic chip configuration.
+    modular_config: ModularConfig,
+    /// Absolute value chip configuration.
+    abs_config: AbsConfig,
+    /// Minimum and maximum chip configuration.
+    min_max_config: MinMaxConfig,
+}
+
+/// Arithmetic configuration implementation.
+#[cfg(test)]
+impl ArithmeticConfig {
+    /// Returns the constant column, needed to lay out the circuit outside of
+    /// a prover.
+    pub fn constant(&self) -> Column<Fixed> {
+        self.constant
+    }
 }
 
 /// Arithmetic chip definition.
@@ -88,29 +240,123 @@ impl<F: FieldExt> ArithmeticChip<F> {
         a: Column<Advice>,
         b: Column<Advice>,
         instance: Column<Instance>,
+        constant: Column<Fixed>,
     ) -> <Self as Chip<F>>::Config {
-        // configure addition chip
-        let add_config = AddChip::configure(meta, a, b);
-        // configure subtraction chip
-        let sub_config = SubChip::configure(meta, a, b);
-        // configure multiplication chip
-        let mul_config = MulChip::configure(meta, a, b);
+        // configure PLONK chip
+        let plonk_config = PlonkChip::configure(meta, a, b);
+        // configure fused multiply-add chip
+        let fma_config = FmaChip::configure(meta, a, b);
+        // configure exponentiation chip
+        let pow_config = PowChip::configure(meta, a, b);
+        // configure private exponentiation chip
+        let private_pow_config = PrivatePowChip::configure(meta, a, b);
+        // configure range check chip
+        let range_config = RangeChip::configure(meta, a, b);
+        // configure comparison chip, sharing the range check gate
+        let compare_config = CompareChip::configure(meta, a, b, range_config.clone());
+        // configure boolean logic chip
+        let logic_config = LogicChip::configure(meta, a, b);
+        // configure conditional select chip
+        let select_config = SelectChip::configure(meta, a, b);
+        // configure bitwise chip, sharing the range check gate
+        let bitwise_config = BitwiseChip::configure(meta, a, b, range_config.clone());
+        // configure wrapping arithmetic chip, sharing the range check gate
+        let wrapping_config = WrappingChip::configure(meta, a, b, range_config.clone());
+        // configure saturating arithmetic chip, composed of the wrapping,
+        // comparison and select gates
+        let saturating_config = SaturatingChip::<F>::configure(
+            wrapping_config.clone(),
+            compare_config.clone(),
+            select_config.clone(),
+        );
+        // configure big integer chip, composed of the plonk, fma and range
+        // check gates
+        let bigint_config = BigIntChip::configure(
+            meta,
+            a,
+            b,
+            plonk_config.clone(),
+            fma_config.clone(),
+            range_config.clone(),
+        );
+        // configure field division chip
+        let div_config = DivChip::configure(meta, a, b);
+        // configure fixed-point chip, sharing the plonk and range check gates
+        let fixed_config =
+            FixedPointChip::configure(meta, a, b, plonk_config.clone(), range_config.clone());
+        // configure rational chip, sharing the range check gate
+        let rational_config = RationalChip::configure(meta, a, b, range_config.clone());
+        // configure square root chip, sharing the range check gate
+        let sqrt_config = SqrtChip::configure(meta, a, b, range_config.clone());
+        // configure greatest common divisor chip, sharing the range check gate
+        let gcd_config = GcdChip::configure(meta, a, b, range_config.clone());
+        // configure modular arithmetic chip, sharing the range check gate
+        let modular_config = ModularChip::configure(meta, a, b, range_config.clone());
+        // configure absolute value chip, composed of the plonk, select and
+        // range check gates
+        let abs_config = AbsChip::<F>::configure(
+            plonk_config.clone(),
+            select_config.clone(),
+            range_config.clone(),
+        );
+        // configure minimum and maximum chip, composed of the comparison,
+        // select and range check gates
+        let min_max_config = MinMaxChip::<F>::configure(
+            compare_config.clone(),
+            select_config.clone(),
+            range_config.clone(),
+        );
 
         // enable instance equality checks
         meta.enable_equality(instance);
 
+        // enable the constant column, constants are copied from it into
+        // advice cells
+        meta.enable_constant(constant);
+
         // return the top-level config
         ArithmeticConfig {
             a,
             b,
             instance,
-            add_config,
-            sub_config,
-            mul_config,
+            constant,
+            plonk_config,
+            fma_config,
+            pow_config,
+            private_pow_config,
+            range_config,
+            compare_config,
+            logic_config,
+            select_config,
+            bitwise_config,
+            wrapping_config,
+            saturating_config,
+            bigint_config,
+            div_config,
+            fixed_config,
+            rational_config,
+            sqrt_config,
+            gcd_config,
+            modular_config,
+            abs_config,
+            min_max_config,
         }
     }
 }
 
+/// Arithmetic chip table loading.
+impl<F: FieldExt> ArithmeticChip<F> {
+    /// Loads the byte operation table of the bitwise chip. Only circuits that
+    /// use bitwise operators need to load it.
+    pub fn load_bitwise_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip and load its table
+        BitwiseChip::<F>::construct(config, ()).load_table(layouter)
+    }
+}
+
 /// Halo2 Chip implementation for ArithmeticChip.
 impl<F: FieldExt> Chip<F> for ArithmeticChip<F> {
     /// Arithmetic configuration.
@@ -156,6 +402,28 @@ impl<F: FieldExt> ArithmeticInstructions<F> for ArithmeticChip<F> {
         )
     }
 
+    /// Loads a constant number into the circuit.
+    fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<<Self as ArithmeticInstructions<F>>::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region of gates and return
+        layouter.assign_region(
+            // region name
+            || "load constant",
+            // assignment
+            |mut region| {
+                region
+                    .assign_advice_from_constant(|| "constant input", config.a, 0, constant)
+                    .map(Number)
+            },
+        )
+    }
+
     /// Exposes a number as a public input to the circuit.
     fn expose_public(
         &self,
@@ -181,17 +449,42 @@ impl<F: FieldExt> AddInstructions<F> for ArithmeticChip<F> {
     fn add(
         &self,
         layouter: &mut impl Layouter<F>,
-        a: Self::Num,
-        b: Self::Num,
+        a: Input<F>,
+        b: Input<F>,
     ) -> Result<Self::Num, Error> {
-        // configure the add chip
-        let config = self.config().add_config.clone();
+        // configure the plonk chip
+        let config = self.config().plonk_config.clone();
 
-        // construct the add chip
-        let add_chip = AddChip::<F>::construct(config, ());
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(config, ());
 
-        // return the result of add_chip's addition gate
-        add_chip.add(layouter, a, b)
+        // return the result of the plonk_chip's gate under the addition
+        // coefficients
+        plonk_chip.apply(layouter, a, b, add::coefficients())
+    }
+}
+
+/// Addition-by-constant instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> AddConstantInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Addition-by-constant instruction definition.
+    fn add_constant(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        // configure the plonk chip
+        let config = self.config().plonk_config.clone();
+
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(config, ());
+
+        // return the result of the plonk_chip's gate under the
+        // addition-by-constant coefficients
+        plonk_chip.apply_unary(layouter, a, add::constant_coefficients(constant))
     }
 }
 
@@ -204,17 +497,18 @@ impl<F: FieldExt> SubInstructions<F> for ArithmeticChip<F> {
     fn sub(
         &self,
         layouter: &mut impl Layouter<F>,
-        a: Self::Num,
-        b: Self::Num,
+        a: Input<F>,
+        b: Input<F>,
     ) -> Result<Self::Num, Error> {
-        // configure the sub chip
-        let config = self.config().sub_config.clone();
+        // configure the plonk chip
+        let config = self.config().plonk_config.clone();
 
-        // construct the sub chip
-        let sub_chip = SubChip::<F>::construct(config, ());
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(config, ());
 
-        // return the result of the sub_chip's subtraction gate
-        sub_chip.sub(layouter, a, b)
+        // return the result of the plonk_chip's gate under the subtraction
+        // coefficients
+        plonk_chip.apply(layouter, a, b, sub::coefficients())
     }
 }
 
@@ -227,16 +521,884 @@ impl<F: FieldExt> MulInstructions<F> for ArithmeticChip<F> {
     fn mul(
         &self,
         layouter: &mut impl Layouter<F>,
-        a: Self::Num,
-        b: Self::Num,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the plonk chip
+        let config = self.config().plonk_config.clone();
+
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(config, ());
+
+        // return the result of the plonk_chip's gate under the multiplication
+        // coefficients
+        plonk_chip.apply(layouter, a, b, mul::coefficients())
+    }
+}
+
+/// Multiplication-by-constant instruction set implementation for
+/// ArithmeticChip.
+impl<F: FieldExt> MulConstantInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Multiplication-by-constant instruction definition.
+    fn mul_constant(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        // configure the plonk chip
+        let config = self.config().plonk_config.clone();
+
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(config, ());
+
+        // return the result of the plonk_chip's gate under the
+        // multiplication-by-constant coefficients
+        plonk_chip.apply_unary(layouter, a, mul::constant_coefficients(constant))
+    }
+}
+
+/// Fused multiply-add instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> FmaInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Fused multiply-add instruction definition.
+    fn fma(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        c: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the fma chip
+        let config = self.config().fma_config.clone();
+
+        // construct the fma chip
+        let fma_chip = FmaChip::<F>::construct(config, ());
+
+        // return the result of the fma_chip's fused multiply-add gate
+        fma_chip.fma(layouter, a, b, c)
+    }
+}
+
+/// Exponentiation instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> PowInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Exponentiation instruction definition.
+    fn pow(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: u64,
     ) -> Result<Self::Num, Error> {
-        // configure the mul chip
-        let config = self.config().mul_config.clone();
+        // configure the pow chip
+        let config = self.config().pow_config.clone();
+
+        // construct the pow chip
+        let pow_chip = PowChip::<F>::construct(config, ());
+
+        // return the result of the pow_chip's square-and-multiply gates
+        pow_chip.pow(layouter, a, exponent)
+    }
+}
+
+/// Private exponentiation instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> PrivatePowInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Private exponentiation instruction definition.
+    fn pow_private(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        // configure the private pow chip
+        let config = self.config().private_pow_config.clone();
+
+        // construct the private pow chip
+        let private_pow_chip = PrivatePowChip::<F>::construct(config, ());
+
+        // return the result of the private_pow_chip's bit decomposition
+        private_pow_chip.pow_private(layouter, a, exponent, bits)
+    }
+}
+
+/// Range check instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> RangeInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Range check instruction definition.
+    fn range_check(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        // configure the range chip
+        let config = self.config().range_config.clone();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config, ());
+
+        // return the result of the range_chip's bit decomposition
+        range_chip.range_check(layouter, a, bits)
+    }
+}
+
+/// Comparison instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> CompareInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Less than instruction definition.
+    fn lt(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the compare chip
+        let config = self.config().compare_config.clone();
+
+        // construct the compare chip
+        let compare_chip = CompareChip::<F>::construct(config, ());
+
+        // return the result of the compare_chip's ordering gate
+        compare_chip.lt(layouter, a, b)
+    }
+
+    /// Less than or equal instruction definition.
+    fn le(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the compare chip
+        let config = self.config().compare_config.clone();
+
+        // construct the compare chip
+        let compare_chip = CompareChip::<F>::construct(config, ());
+
+        // return the result of the compare_chip's ordering gate
+        compare_chip.le(layouter, a, b)
+    }
+
+    /// Equality instruction definition.
+    fn eq(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the compare chip
+        let config = self.config().compare_config.clone();
+
+        // construct the compare chip
+        let compare_chip = CompareChip::<F>::construct(config, ());
+
+        // return the result of the compare_chip's is-zero gate
+        compare_chip.eq(layouter, a, b)
+    }
+
+    /// Inequality instruction definition.
+    fn ne(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the compare chip
+        let config = self.config().compare_config.clone();
+
+        // construct the compare chip
+        let compare_chip = CompareChip::<F>::construct(config, ());
+
+        // return the result of the compare_chip's is-zero gate
+        compare_chip.ne(layouter, a, b)
+    }
+}
+
+/// Boolean logic instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> LogicInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Logical and instruction definition.
+    fn and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the logic chip
+        let config = self.config().logic_config.clone();
+
+        // construct the logic chip
+        let logic_chip = LogicChip::<F>::construct(config, ());
+
+        // return the result of the logic_chip's and gate
+        logic_chip.and(layouter, a, b)
+    }
+
+    /// Logical or instruction definition.
+    fn or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the logic chip
+        let config = self.config().logic_config.clone();
+
+        // construct the logic chip
+        let logic_chip = LogicChip::<F>::construct(config, ());
+
+        // return the result of the logic_chip's or gate
+        logic_chip.or(layouter, a, b)
+    }
+
+    /// Logical not instruction definition.
+    fn not(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        // configure the logic chip
+        let config = self.config().logic_config.clone();
+
+        // construct the logic chip
+        let logic_chip = LogicChip::<F>::construct(config, ());
+
+        // return the result of the logic_chip's not gate
+        logic_chip.not(layouter, a)
+    }
+}
+
+/// Conditional select instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> SelectInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Conditional select instruction definition.
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        condition: Input<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the select chip
+        let config = self.config().select_config.clone();
+
+        // construct the select chip
+        let select_chip = SelectChip::<F>::construct(config, ());
+
+        // return the result of the select_chip's gate
+        select_chip.select(layouter, condition, a, b)
+    }
+}
+
+/// Field division instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> DivInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Field division instruction definition.
+    fn div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the div chip
+        let config = self.config().div_config.clone();
+
+        // construct the div chip
+        let div_chip = DivChip::<F>::construct(config, ());
+
+        // return the result of the div_chip's inverse gate
+        div_chip.div(layouter, a, b)
+    }
+}
+
+/// Fixed-point instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> FixedPointInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Fixed-point multiplication instruction definition.
+    fn fixed_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        format: FixedPoint,
+    ) -> Result<Self::Num, Error> {
+        // configure the fixed-point chip
+        let config = self.config().fixed_config.clone();
+
+        // construct the fixed-point chip
+        let fixed_chip = FixedPointChip::<F>::construct(config, ());
+
+        // return the result of the fixed_chip's rescale gate
+        fixed_chip.fixed_mul(layouter, a, b, format)
+    }
+}
+
+/// Rational instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> RationalInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Rational addition instruction definition.
+    fn rational_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // configure the rational chip
+        let config = self.config().rational_config.clone();
+
+        // construct the rational chip
+        let rational_chip = RationalChip::<F>::construct(config, ());
+
+        // return the result of the rational_chip's cross-multiplication gate
+        rational_chip.rational_add(layouter, a, b)
+    }
+
+    /// Rational subtraction instruction definition.
+    fn rational_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // configure the rational chip
+        let config = self.config().rational_config.clone();
+
+        // construct the rational chip
+        let rational_chip = RationalChip::<F>::construct(config, ());
+
+        // return the result of the rational_chip's cross-multiplication gate
+        rational_chip.rational_sub(layouter, a, b)
+    }
+
+    /// Rational multiplication instruction definition.
+    fn rational_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // configure the rational chip
+        let config = self.config().rational_config.clone();
+
+        // construct the rational chip
+        let rational_chip = RationalChip::<F>::construct(config, ());
+
+        // return the result of the rational_chip's cross-multiplication gate
+        rational_chip.rational_mul(layouter, a, b)
+    }
+
+    /// Rational division instruction definition.
+    fn rational_div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // configure the rational chip
+        let config = self.config().rational_config.clone();
+
+        // construct the rational chip
+        let rational_chip = RationalChip::<F>::construct(config, ());
+
+        // return the result of the rational_chip's cross-multiplication gate
+        rational_chip.rational_div(layouter, a, b)
+    }
+
+    /// Rational reduction instruction definition.
+    fn reduce(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // configure the rational chip
+        let config = self.config().rational_config.clone();
+
+        // construct the rational chip
+        let rational_chip = RationalChip::<F>::construct(config, ());
+
+        // return the result of the rational_chip's reduction gate
+        rational_chip.reduce(layouter, a)
+    }
+}
+
+/// Square root instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> SqrtInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Square root instruction definition.
+    fn sqrt(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        // configure the square root chip
+        let config = self.config().sqrt_config.clone();
+
+        // construct the square root chip
+        let sqrt_chip = SqrtChip::<F>::construct(config, ());
+
+        // return the result of the sqrt_chip's square root gate
+        sqrt_chip.sqrt(layouter, a)
+    }
+}
+
+/// Greatest common divisor instruction set implementation for
+/// ArithmeticChip.
+impl<F: FieldExt> GcdInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Greatest common divisor instruction definition.
+    fn gcd(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the greatest common divisor chip
+        let config = self.config().gcd_config.clone();
+
+        // construct the greatest common divisor chip
+        let gcd_chip = GcdChip::<F>::construct(config, ());
+
+        // return the result of the gcd_chip's divisor gate
+        gcd_chip.gcd(layouter, a, b)
+    }
+
+    /// Least common multiple instruction definition.
+    fn lcm(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the greatest common divisor chip
+        let config = self.config().gcd_config.clone();
+
+        // construct the greatest common divisor chip
+        let gcd_chip = GcdChip::<F>::construct(config, ());
+
+        // return the result of the gcd_chip's multiple gate
+        gcd_chip.lcm(layouter, a, b)
+    }
+}
+
+/// Absolute value instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> AbsInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Absolute value instruction definition.
+    fn abs(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        // configure the absolute value chip
+        let config = self.config().abs_config.clone();
+
+        // construct the absolute value chip
+        let abs_chip = AbsChip::<F>::construct(config, ());
+
+        // return the result of the abs_chip's signed select
+        abs_chip.abs(layouter, a)
+    }
+}
+
+/// Minimum and maximum instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> MinMaxInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Minimum instruction definition.
+    fn min(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Input<F>],
+    ) -> Result<Self::Num, Error> {
+        // configure the minimum and maximum chip
+        let config = self.config().min_max_config.clone();
+
+        // construct the minimum and maximum chip
+        let min_max_chip = MinMaxChip::<F>::construct(config, ());
+
+        // return the result of the min_max_chip's folded selects
+        min_max_chip.min(layouter, inputs)
+    }
+
+    /// Maximum instruction definition.
+    fn max(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Input<F>],
+    ) -> Result<Self::Num, Error> {
+        // configure the minimum and maximum chip
+        let config = self.config().min_max_config.clone();
+
+        // construct the minimum and maximum chip
+        let min_max_chip = MinMaxChip::<F>::construct(config, ());
+
+        // return the result of the min_max_chip's folded selects
+        min_max_chip.max(layouter, inputs)
+    }
+}
+
+/// Modular arithmetic instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> ModularInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Modular reduction instruction definition.
+    fn reduce_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // configure the modular arithmetic chip
+        let config = self.config().modular_config.clone();
+
+        // construct the modular arithmetic chip
+        let modular_chip = ModularChip::<F>::construct(config, ());
+
+        // return the result of the modular_chip's reduction gate
+        modular_chip.reduce_mod(layouter, a, modulus)
+    }
+
+    /// Modular addition instruction definition.
+    fn add_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // configure the modular arithmetic chip
+        let config = self.config().modular_config.clone();
+
+        // construct the modular arithmetic chip
+        let modular_chip = ModularChip::<F>::construct(config, ());
+
+        // return the result of the modular_chip's addition gate
+        modular_chip.add_mod(layouter, a, b, modulus)
+    }
+
+    /// Modular subtraction instruction definition.
+    fn sub_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // configure the modular arithmetic chip
+        let config = self.config().modular_config.clone();
+
+        // construct the modular arithmetic chip
+        let modular_chip = ModularChip::<F>::construct(config, ());
+
+        // return the result of the modular_chip's subtraction gate
+        modular_chip.sub_mod(layouter, a, b, modulus)
+    }
+
+    /// Modular multiplication instruction definition.
+    fn mul_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // configure the modular arithmetic chip
+        let config = self.config().modular_config.clone();
+
+        // construct the modular arithmetic chip
+        let modular_chip = ModularChip::<F>::construct(config, ());
+
+        // return the result of the modular_chip's multiplication gate
+        modular_chip.mul_mod(layouter, a, b, modulus)
+    }
+
+    /// Modular exponentiation instruction definition.
+    fn pow_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Operand,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // configure the modular arithmetic chip
+        let config = self.config().modular_config.clone();
+
+        // construct the modular arithmetic chip
+        let modular_chip = ModularChip::<F>::construct(config, ());
+
+        // return the result of the modular_chip's exponentiation gate
+        modular_chip.pow_mod(layouter, a, exponent, modulus)
+    }
+}
+
+/// Bitwise instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> BitwiseInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Bitwise and instruction definition.
+    fn bit_and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip
+        let bitwise_chip = BitwiseChip::<F>::construct(config, ());
+
+        // return the result of the bitwise_chip's byte lookups
+        bitwise_chip.bit_and(layouter, a, b)
+    }
+
+    /// Bitwise or instruction definition.
+    fn bit_or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip
+        let bitwise_chip = BitwiseChip::<F>::construct(config, ());
+
+        // return the result of the bitwise_chip's byte lookups
+        bitwise_chip.bit_or(layouter, a, b)
+    }
+
+    /// Bitwise xor instruction definition.
+    fn bit_xor(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip
+        let bitwise_chip = BitwiseChip::<F>::construct(config, ());
+
+        // return the result of the bitwise_chip's byte lookups
+        bitwise_chip.bit_xor(layouter, a, b)
+    }
+}
+
+/// Shift instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> ShiftInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Left shift instruction definition.
+    fn shl(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip
+        let bitwise_chip = BitwiseChip::<F>::construct(config, ());
+
+        // return the result of the bitwise_chip's shift gate
+        bitwise_chip.shl(layouter, a, n)
+    }
+
+    /// Right shift instruction definition.
+    fn shr(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error> {
+        // configure the bitwise chip
+        let config = self.config().bitwise_config.clone();
+
+        // construct the bitwise chip
+        let bitwise_chip = BitwiseChip::<F>::construct(config, ());
+
+        // return the result of the bitwise_chip's shift gate
+        bitwise_chip.shr(layouter, a, n)
+    }
+}
+
+/// Wrapping arithmetic instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> WrappingInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Wrapping addition instruction definition.
+    fn wrapping_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the wrapping chip
+        let config = self.config().wrapping_config.clone();
+
+        // construct the wrapping chip
+        let wrapping_chip = WrappingChip::<F>::construct(config, ());
+
+        // return the result of the wrapping_chip's wrapping gate
+        wrapping_chip.wrapping_add(layouter, a, b)
+    }
+
+    /// Wrapping subtraction instruction definition.
+    fn wrapping_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the wrapping chip
+        let config = self.config().wrapping_config.clone();
+
+        // construct the wrapping chip
+        let wrapping_chip = WrappingChip::<F>::construct(config, ());
+
+        // return the result of the wrapping_chip's wrapping gate
+        wrapping_chip.wrapping_sub(layouter, a, b)
+    }
+
+    /// Wrapping multiplication instruction definition.
+    fn wrapping_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the wrapping chip
+        let config = self.config().wrapping_config.clone();
+
+        // construct the wrapping chip
+        let wrapping_chip = WrappingChip::<F>::construct(config, ());
+
+        // return the result of the wrapping_chip's wrapping gate
+        wrapping_chip.wrapping_mul(layouter, a, b)
+    }
+}
+
+/// Saturating arithmetic instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> SaturatingInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Saturating addition instruction definition.
+    fn saturating_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the saturating chip
+        let config = self.config().saturating_config.clone();
+
+        // construct the saturating chip
+        let saturating_chip = SaturatingChip::<F>::construct(config, ());
+
+        // return the clamped result of the saturating_chip
+        saturating_chip.saturating_add(layouter, a, b)
+    }
+
+    /// Saturating subtraction instruction definition.
+    fn saturating_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the saturating chip
+        let config = self.config().saturating_config.clone();
+
+        // construct the saturating chip
+        let saturating_chip = SaturatingChip::<F>::construct(config, ());
+
+        // return the clamped result of the saturating_chip
+        saturating_chip.saturating_sub(layouter, a, b)
+    }
+
+    /// Saturating multiplication instruction definition.
+    fn saturating_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // configure the saturating chip
+        let config = self.config().saturating_config.clone();
+
+        // construct the saturating chip
+        let saturating_chip = SaturatingChip::<F>::construct(config, ());
+
+        // return the clamped result of the saturating_chip
+        saturating_chip.saturating_mul(layouter, a, b)
+    }
+}
+
+/// Big integer instruction set implementation for ArithmeticChip.
+impl<F: FieldExt> BigIntInstructions<F> for ArithmeticChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Big integer addition instruction definition.
+    fn big_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        // configure the bigint chip
+        let config = self.config().bigint_config.clone();
+
+        // construct the bigint chip
+        let bigint_chip = BigIntChip::<F>::construct(config, ());
+
+        // return the limbs of the bigint_chip's schoolbook addition
+        bigint_chip.big_add(layouter, a, b)
+    }
+
+    /// Big integer multiplication instruction definition.
+    fn big_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        // configure the bigint chip
+        let config = self.config().bigint_config.clone();
 
-        // construct the mul chip
-        let mul_chip = MulChip::<F>::construct(config, ());
+        // construct the bigint chip
+        let bigint_chip = BigIntChip::<F>::construct(config, ());
 
-        // return the result of the mul_chip's multiplication gate
-        mul_chip.mul(layouter, a, b)
+        // return the limbs of the bigint_chip's schoolbook multiplication
+        bigint_chip.big_mul(layouter, a, b)
     }
 }
@@ -0,0 +1,313 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    bigint::LIMB_BITS,
+    chips::{
+        add,
+        arithmetic::{Input, Number},
+        fma::{FmaChip, FmaConfig, FmaInstructions},
+        mul,
+        plonk::{PlonkChip, PlonkConfig, PlonkInstructions},
+        range::{RangeChip, RangeConfig, RangeInstructions},
+    },
+};
+
+/// Big integer instruction set.
+/// Big integers are little-endian u64 limbs, and every instruction expects
+/// range checked input limbs and returns range checked output limbs.
+pub trait BigIntInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Big integer addition instruction.
+    /// Takes two big integers of `n` and `m` limbs and returns their sum in
+    /// `max(n, m) + 1` limbs.
+    fn big_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error>;
+
+    /// Big integer multiplication instruction.
+    /// Takes two big integers of `n` and `m` limbs and returns their product
+    /// in `n + m` limbs.
+    fn big_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error>;
+}
+
+/// Big integer chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct BigIntConfig {
+    /// Advice column for the column sum and the limb.
+    a: Column<Advice>,
+    /// Advice column for the carry.
+    b: Column<Advice>,
+    /// Carry selector.
+    sel_carry: Selector,
+    /// PLONK chip configuration, for column sums.
+    plonk_config: PlonkConfig,
+    /// Fused multiply-add chip configuration, for column sums of products.
+    fma_config: FmaConfig,
+    /// Range check chip configuration, for limbs and carries.
+    range_config: RangeConfig,
+}
+
+/// Big integer chip definition.
+///
+/// Each column `k` of the schoolbook algorithm sums its limbs, or limb
+/// products, in the field together with the carry of column `k - 1`, then
+/// splits the sum into a limb and the next carry,
+///
+/// | a     | b     |
+/// |-------|-------|
+/// | sum   | carry |
+/// | limb  |       |
+///
+/// constraining `sum = carry·2^64 + limb` with the limb range checked to 64
+/// bits and the carry to the bits it can reach. No column sum comes close to
+/// the field modulus, so the limbs are the exact integer result, even if the
+/// integer itself exceeds the field.
+pub struct BigIntChip<F: FieldExt> {
+    /// Big integer configuration.
+    config: BigIntConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Big integer chip implementation.
+impl<F: FieldExt> BigIntChip<F> {
+    /// Construct BigIntChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure BigIntChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        plonk_config: PlonkConfig,
+        fma_config: FmaConfig,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_carry = meta.selector();
+
+        // define the carry gate
+        meta.create_gate(
+            // gate name
+            "carry",
+            // gate logic
+            |meta| {
+                // query the sum and the carry from the current rotation
+                let sum = meta.query_advice(a, Rotation::cur());
+                let carry = meta.query_advice(b, Rotation::cur());
+                // query the limb from the next rotation
+                let limb = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_carry = meta.query_selector(sel_carry);
+
+                let base = Expression::Constant(F::from_u128(1 << LIMB_BITS));
+
+                // if `sel_carry != 0`, then `sum = carry·2^64 + limb`
+                vec![sel_carry * (sum - carry * base - limb)]
+            },
+        );
+
+        // return config
+        BigIntConfig {
+            a,
+            b,
+            sel_carry,
+            plonk_config,
+            fma_config,
+            range_config,
+        }
+    }
+
+    /// Splits a column sum into a 64-bit limb and a carry of `carry_bits`
+    /// bits, both range checked.
+    fn split(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        sum: Input<F>,
+        carry_bits: usize,
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the limb and the carry, a column sum may exceed 128 bits,
+        // so the carry is divided out in the field
+        let limb = sum.value().map(|sum| F::from(sum.get_lower_128() as u64));
+        let carry = sum
+            .value()
+            .zip(limb)
+            .map(|(sum, limb)| (sum - limb) * F::from_u128(1 << LIMB_BITS).invert().unwrap());
+
+        let (limb, carry) = layouter.assign_region(
+            // region name
+            || "carry",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the carry gate, set at region offset zero, it will
+                // constrain cells zero and one
+                config.sel_carry.enable(&mut region, 0)?;
+
+                // assign the sum and the carry to offset zero
+                sum.assign("sum", &mut region, config.a, 0)?;
+                let carry = region.assign_advice(|| "carry", config.b, 0, || carry)?;
+
+                // assign the limb to offset one
+                let limb = region.assign_advice(|| "limb", config.a, 1, || limb)?;
+
+                Ok((Number(limb), Number(carry)))
+            },
+        )?;
+
+        // the limb and the carry must fit
+        let limb = range_chip.range_check(layouter, limb.into(), LIMB_BITS)?;
+        let carry = range_chip.range_check(layouter, carry.into(), carry_bits)?;
+
+        Ok((limb, carry))
+    }
+}
+
+/// Halo2 Chip implementation for BigIntChip.
+impl<F: FieldExt> Chip<F> for BigIntChip<F> {
+    /// Big integer configuration.
+    type Config = BigIntConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Big integer instruction set implementation for BigIntChip.
+impl<F: FieldExt> BigIntInstructions<F> for BigIntChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Big integer addition instruction implementation.
+    fn big_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        // construct the plonk chip
+        let plonk_chip = PlonkChip::<F>::construct(self.config().plonk_config.clone(), ());
+
+        let mut limbs = Vec::new();
+        let mut carry: Option<Number<F>> = None;
+
+        for i in 0..a.len().max(b.len()) {
+            // sum the limbs of the column and the previous carry, a sum of
+            // two limbs and a boolean carry carries at most one
+            let mut terms = a.get(i).into_iter().chain(b.get(i)).cloned();
+            let mut sum = terms.next().ok_or(Error::Synthesis)?;
+            for term in terms.chain(carry.take().map(Input::from)) {
+                sum = plonk_chip
+                    .apply(layouter, sum, term, add::coefficients())?
+                    .into();
+            }
+
+            let (limb, next) = self.split(layouter, sum, 1)?;
+            limbs.push(limb);
+            carry = Some(next);
+        }
+
+        // the last carry is the top limb
+        limbs.extend(carry);
+
+        Ok(limbs)
+    }
+
+    /// Big integer multiplication instruction implementation.
+    fn big_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &[Input<F>],
+        b: &[Input<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        if a.is_empty() || b.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        // construct the plonk and fma chips
+        let plonk_chip = PlonkChip::<F>::construct(self.config().plonk_config.clone(), ());
+        let fma_chip = FmaChip::<F>::construct(self.config().fma_config.clone(), ());
+
+        // a column sums at most `min(n, m)` products of limbs, so its carry
+        // stays below `(min(n, m) + 1)·2^64`
+        let products = a.len().min(b.len());
+        let carry_bits = LIMB_BITS + (usize::BITS - products.leading_zeros()) as usize;
+
+        let columns = a.len() + b.len() - 1;
+        let mut limbs = Vec::new();
+        let mut carry: Option<Number<F>> = None;
+
+        for k in 0..columns {
+            // accumulate the products of the column onto the previous carry
+            let mut sum = carry.take().map(Input::from);
+            for i in k.saturating_sub(b.len() - 1)..=k.min(a.len() - 1) {
+                let (lhs, rhs) = (a[i].clone(), b[k - i].clone());
+                sum = Some(match sum {
+                    Some(sum) => fma_chip.fma(layouter, lhs, rhs, sum)?.into(),
+                    None => plonk_chip
+                        .apply(layouter, lhs, rhs, mul::coefficients())?
+                        .into(),
+                });
+            }
+
+            // the product fits into `n + m` limbs, so the last carry is a limb
+            let bits = match k == columns - 1 {
+                true => LIMB_BITS,
+                false => carry_bits,
+            };
+            let (limb, next) = self.split(layouter, sum.ok_or(Error::Synthesis)?, bits)?;
+            limbs.push(limb);
+            carry = Some(next);
+        }
+
+        // the last carry is the top limb
+        limbs.extend(carry);
+
+        Ok(limbs)
+    }
+}
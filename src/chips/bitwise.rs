@@ -0,0 +1,559 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    range::{RangeChip, RangeConfig, RangeInstructions},
+};
+
+/// Bit width of bitwise operands, bitwise operators are over u64.
+pub const BITS: usize = 64;
+
+/// Number of bytes in an operand, the lookup table works on 8-bit chunks.
+const BYTES: usize = BITS / 8;
+
+/// Bitwise instruction set.
+pub trait BitwiseInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Bitwise and instruction.
+    /// Takes two u64 inputs and returns `a & b`.
+    fn bit_and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Bitwise or instruction.
+    /// Takes two u64 inputs and returns `a | b`.
+    fn bit_or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Bitwise xor instruction.
+    /// Takes two u64 inputs and returns `a xor b`.
+    fn bit_xor(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Shift instruction set.
+pub trait ShiftInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Left shift instruction.
+    /// Takes a u64 input and a public shift below 64 and returns `a << n`,
+    /// discarding the bits shifted out of the u64.
+    fn shl(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error>;
+
+    /// Right shift instruction.
+    /// Takes a u64 input and a public shift below 64 and returns `a >> n`.
+    fn shr(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Byte operation lookup table, one row per pair of bytes, `2^16` rows.
+#[derive(Clone, Debug)]
+pub struct ByteTable {
+    /// Left byte.
+    lhs: TableColumn,
+    /// Right byte.
+    rhs: TableColumn,
+    /// `lhs & rhs`.
+    and: TableColumn,
+    /// `lhs | rhs`.
+    or: TableColumn,
+    /// `lhs ^ rhs`.
+    xor: TableColumn,
+}
+
+/// Bitwise chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct BitwiseConfig {
+    /// Advice column for running sums and shift inputs and outputs.
+    a: Column<Advice>,
+    /// Advice column for bytes and shift quotients.
+    b: Column<Advice>,
+    /// Fixed column for the shift factor `2^n`.
+    power: Column<Fixed>,
+    /// Byte decomposition selector.
+    sel_bytes: Selector,
+    /// Bitwise and lookup selector.
+    sel_and: Selector,
+    /// Bitwise or lookup selector.
+    sel_or: Selector,
+    /// Bitwise xor lookup selector.
+    sel_xor: Selector,
+    /// Left shift selector.
+    sel_shl: Selector,
+    /// Right shift selector.
+    sel_shr: Selector,
+    /// Byte operation lookup table.
+    table: ByteTable,
+    /// Range check chip configuration, for shifts.
+    range_config: RangeConfig,
+}
+
+/// Bitwise chip definition.
+///
+/// Bitwise operators decompose both operands and the output into bytes,
+/// least significant byte first, with running sums
+/// `z_i = 256·z_{i+1} + byte_i`.
+/// Each byte takes three rows, one for each of the operands and the output,
+///
+/// | a      | b         |
+/// |--------|-----------|
+/// | z_i(a) | byte_i(a) |
+/// | z_i(b) | byte_i(b) |
+/// | z_i(c) | byte_i(c) |
+///
+/// and the byte triple is looked up in a precomputed table of 8-bit results.
+/// The final running sums are constrained to zero, so all three are u64s.
+/// The table has `2^16` rows, so circuits with bitwise operators need
+/// `k >= 17`.
+///
+/// Shifts split the operand at bit `n` with range checks. `a >> n` witnesses
+/// `a = q·2^n + r` with `r < 2^n` and `q < 2^(64-n)`, `a << n` witnesses
+/// `a·2^n = h·2^64 + c` with `h < 2^n` and `c < 2^64`.
+pub struct BitwiseChip<F: FieldExt> {
+    /// Bitwise configuration.
+    config: BitwiseConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Bitwise chip implementation.
+impl<F: FieldExt> BitwiseChip<F> {
+    /// Construct BitwiseChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure BitwiseChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get fixed column for the shift factor
+        let power = meta.fixed_column();
+
+        // get selectors, lookup selectors must be complex selectors
+        let sel_bytes = meta.selector();
+        let sel_and = meta.complex_selector();
+        let sel_or = meta.complex_selector();
+        let sel_xor = meta.complex_selector();
+        let sel_shl = meta.selector();
+        let sel_shr = meta.selector();
+
+        // get lookup table columns
+        let table = ByteTable {
+            lhs: meta.lookup_table_column(),
+            rhs: meta.lookup_table_column(),
+            and: meta.lookup_table_column(),
+            or: meta.lookup_table_column(),
+            xor: meta.lookup_table_column(),
+        };
+
+        // define the byte decomposition gate
+        meta.create_gate(
+            // gate name
+            "bytes",
+            // gate logic
+            |meta| {
+                // query selector
+                let sel_bytes = meta.query_selector(sel_bytes);
+
+                // if `sel_bytes != 0`, then `z_i = 256·z_{i+1} + byte_i` is
+                // constrained for both operands and the output
+                (0..3)
+                    .map(|i| {
+                        let z = meta.query_advice(a, Rotation(i));
+                        let byte = meta.query_advice(b, Rotation(i));
+                        let next_z = meta.query_advice(a, Rotation(i + 3));
+
+                        sel_bytes.clone() * (z - next_z * Expression::Constant(F::from(256)) - byte)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        // look up the byte triple in the table of the selected operation, a
+        // disabled selector looks up `(0, 0, 0)`, which is in every table
+        for (selector, out) in [
+            (sel_and, table.and),
+            (sel_or, table.or),
+            (sel_xor, table.xor),
+        ] {
+            meta.lookup(|meta| {
+                let selector = meta.query_selector(selector);
+                let lhs = meta.query_advice(b, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::next());
+                let byte = meta.query_advice(b, Rotation(2));
+
+                vec![
+                    (selector.clone() * lhs, table.lhs),
+                    (selector.clone() * rhs, table.rhs),
+                    (selector * byte, out),
+                ]
+            });
+        }
+
+        // define the shift gate
+        meta.create_gate(
+            // gate name
+            "shift",
+            // gate logic
+            |meta| {
+                // query the operand and the quotient or high bits from the
+                // current rotation
+                let value = meta.query_advice(a, Rotation::cur());
+                let quotient = meta.query_advice(b, Rotation::cur());
+                // query the remainder or output from the next rotation
+                let rest = meta.query_advice(a, Rotation::next());
+                // query the shift factor
+                let power = meta.query_fixed(power, Rotation::cur());
+                // query selectors
+                let sel_shl = meta.query_selector(sel_shl);
+                let sel_shr = meta.query_selector(sel_shr);
+
+                let range = Expression::Constant(F::from_u128(1 << BITS));
+
+                // if `sel_shl != 0`, then `value·2^n = high·2^64 + out`.
+                // if `sel_shr != 0`, then `value = quotient·2^n + remainder`.
+                vec![
+                    sel_shl
+                        * (value.clone() * power.clone() - quotient.clone() * range - rest.clone()),
+                    sel_shr * (value - quotient * power - rest),
+                ]
+            },
+        );
+
+        // return config
+        BitwiseConfig {
+            a,
+            b,
+            power,
+            sel_bytes,
+            sel_and,
+            sel_or,
+            sel_xor,
+            sel_shl,
+            sel_shr,
+            table,
+            range_config,
+        }
+    }
+
+    /// Loads the byte operation table. Only circuits that use bitwise
+    /// operators need it, a table that is not loaded is all zeros.
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        // get config
+        let config = self.config();
+
+        layouter.assign_table(
+            // table name
+            || "byte table",
+            // assignment
+            |mut table| {
+                for (row, (lhs, rhs)) in (0..256u64)
+                    .flat_map(|lhs| (0..256u64).map(move |rhs| (lhs, rhs)))
+                    .enumerate()
+                {
+                    for (column, value) in [
+                        (config.table.lhs, lhs),
+                        (config.table.rhs, rhs),
+                        (config.table.and, lhs & rhs),
+                        (config.table.or, lhs | rhs),
+                        (config.table.xor, lhs ^ rhs),
+                    ] {
+                        table.assign_cell(
+                            || "byte",
+                            column,
+                            row,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    /// Assigns a byte decomposition region under the given lookup selector
+    /// and returns the output.
+    fn apply(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        selector: Selector,
+        op: fn(u64, u64) -> u64,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // compute the output, operands that are not u64 fail the
+        // decomposition
+        let out = a
+            .value()
+            .zip(b.value())
+            .map(|(a, b)| F::from(op(a.get_lower_128() as u64, b.get_lower_128() as u64)));
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "bitwise",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // assign the operands and the output as the first running sums
+                // to offsets zero to two, column a of the region
+                a.assign("lhs", &mut region, config.a, 0)?;
+                b.assign("rhs", &mut region, config.a, 1)?;
+                let c = region.assign_advice(|| "out", config.a, 2, || out)?;
+
+                for (offset, value) in [a.value(), b.value(), out].into_iter().enumerate() {
+                    let value = value.map(|v| v.get_lower_128() as u64);
+
+                    for byte in 0..BYTES {
+                        let row = byte * 3 + offset;
+
+                        // witness the byte
+                        region.assign_advice(
+                            || "byte",
+                            config.b,
+                            row,
+                            || value.map(|v| F::from(v >> (8 * byte) & 0xff)),
+                        )?;
+
+                        // witness the next running sum, the last one must be
+                        // zero
+                        let z = region.assign_advice(
+                            || "z",
+                            config.a,
+                            row + 3,
+                            || value.map(|v| F::from_u128(v as u128 >> (8 * (byte + 1)))),
+                        )?;
+                        if byte == BYTES - 1 {
+                            region.constrain_constant(z.cell(), F::zero())?;
+                        }
+                    }
+                }
+
+                // enable the decomposition gate and the lookup once per byte
+                for byte in 0..BYTES {
+                    config.sel_bytes.enable(&mut region, byte * 3)?;
+                    selector.enable(&mut region, byte * 3)?;
+                }
+
+                // return the output
+                Ok(Number(c))
+            },
+        )
+    }
+}
+
+/// Halo2 Chip implementation for BitwiseChip.
+impl<F: FieldExt> Chip<F> for BitwiseChip<F> {
+    /// Bitwise configuration.
+    type Config = BitwiseConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Bitwise instruction set implementation for BitwiseChip.
+impl<F: FieldExt> BitwiseInstructions<F> for BitwiseChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Bitwise and instruction implementation.
+    fn bit_and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.apply(layouter, a, b, self.config().sel_and, |a, b| a & b)
+    }
+
+    /// Bitwise or instruction implementation.
+    fn bit_or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.apply(layouter, a, b, self.config().sel_or, |a, b| a | b)
+    }
+
+    /// Bitwise xor instruction implementation.
+    fn bit_xor(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.apply(layouter, a, b, self.config().sel_xor, |a, b| a ^ b)
+    }
+}
+
+/// Shift instruction set implementation for BitwiseChip.
+impl<F: FieldExt> ShiftInstructions<F> for BitwiseChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Left shift instruction implementation.
+    fn shl(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // the operand must be a u64
+        let a: Input<F> = range_chip.range_check(layouter, a, BITS)?.into();
+
+        // witness the bits shifted out and the output
+        let value = a.value().map(|a| a.get_lower_128() << n);
+        let high = value.map(|v| F::from_u128(v >> BITS));
+        let out = value.map(|v| F::from_u128(v & u64::MAX as u128));
+
+        let (high, out) = layouter.assign_region(
+            // region name
+            || "shl",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the shift gate, set at region offset zero, it will
+                // constrain cells zero and one
+                config.sel_shl.enable(&mut region, 0)?;
+
+                // assign the shift factor
+                region.assign_fixed(
+                    || "power",
+                    config.power,
+                    0,
+                    || Value::known(F::from_u128(1 << n)),
+                )?;
+
+                // assign the operand and the bits shifted out to offset zero
+                a.assign("value", &mut region, config.a, 0)?;
+                let high = region.assign_advice(|| "high", config.b, 0, || high)?;
+
+                // assign the output to offset one
+                let out = region.assign_advice(|| "out", config.a, 1, || out)?;
+
+                Ok((Number(high), Number(out)))
+            },
+        )?;
+
+        // the bits shifted out and the output must fit
+        range_chip.range_check(layouter, high.into(), n)?;
+        range_chip.range_check(layouter, out.into(), BITS)
+    }
+
+    /// Right shift instruction implementation.
+    fn shr(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        n: usize,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the quotient and the remainder
+        let value = a.value().map(|a| a.get_lower_128());
+        let quotient = value.map(|v| F::from_u128(v >> n));
+        let remainder = value.map(|v| F::from_u128(v & ((1 << n) - 1)));
+
+        let (quotient, remainder) = layouter.assign_region(
+            // region name
+            || "shr",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the shift gate, set at region offset zero, it will
+                // constrain cells zero and one
+                config.sel_shr.enable(&mut region, 0)?;
+
+                // assign the shift factor
+                region.assign_fixed(
+                    || "power",
+                    config.power,
+                    0,
+                    || Value::known(F::from_u128(1 << n)),
+                )?;
+
+                // assign the operand and the quotient to offset zero
+                a.assign("value", &mut region, config.a, 0)?;
+                let quotient = region.assign_advice(|| "quotient", config.b, 0, || quotient)?;
+
+                // assign the remainder to offset one
+                let remainder = region.assign_advice(|| "remainder", config.a, 1, || remainder)?;
+
+                Ok((Number(quotient), Number(remainder)))
+            },
+        )?;
+
+        // the remainder and the quotient must fit, which makes the operand a
+        // u64 as well
+        range_chip.range_check(layouter, remainder.into(), n)?;
+        range_chip.range_check(layouter, quotient.into(), BITS - n)
+    }
+}
@@ -0,0 +1,410 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    range::{RangeChip, RangeConfig, RangeInstructions},
+};
+
+/// Bit width of compared values, comparisons are over u64.
+pub const BITS: usize = 64;
+
+/// Comparison instruction set.
+/// Each instruction returns a boolean number, `1` if the comparison holds and
+/// `0` otherwise.
+pub trait CompareInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Less than instruction.
+    /// Takes two u64 inputs and returns `a < b`.
+    fn lt(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Less than or equal instruction.
+    /// Takes two u64 inputs and returns `a <= b`.
+    fn le(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Equality instruction.
+    /// Takes two inputs and returns `a == b`.
+    fn eq(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Inequality instruction.
+    /// Takes two inputs and returns `a != b`.
+    fn ne(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Comparison chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct CompareConfig {
+    /// Advice column for `input_a` and the difference or output.
+    a: Column<Advice>,
+    /// Advice column for `input_b` and the output or inverse.
+    b: Column<Advice>,
+    /// Less than selector.
+    sel_lt: Selector,
+    /// Less than or equal selector.
+    sel_le: Selector,
+    /// Equality selector.
+    sel_eq: Selector,
+    /// Inequality selector.
+    sel_ne: Selector,
+    /// Range check chip configuration, for the operands and the difference.
+    range_config: RangeConfig,
+}
+
+/// Comparison chip definition.
+///
+/// Orderings witness a boolean `lt` and the difference
+/// `diff = a - b + lt·2^64` (`a - b - 1 + lt·2^64` for `<=`). With both
+/// operands range checked to u64, `diff` fits into 64 bits only for the
+/// correct `lt`, which the range check on `diff` enforces.
+///
+/// Equalities are an is-zero gadget on `d = a - b`, witnessing
+/// `inv = d^-1` (or zero) with `out = 1 - d·inv` and `d·out = 0`.
+pub struct CompareChip<F: FieldExt> {
+    /// Comparison configuration.
+    config: CompareConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Comparison chip implementation.
+impl<F: FieldExt> CompareChip<F> {
+    /// Construct CompareChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure CompareChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_lt = meta.selector();
+        let sel_le = meta.selector();
+        let sel_eq = meta.selector();
+        let sel_ne = meta.selector();
+
+        // define the ordering gate
+        meta.create_gate(
+            // gate name
+            "ordering",
+            // gate logic
+            |meta| {
+                // query the operands from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the difference and the output from the next rotation
+                let diff = meta.query_advice(a, Rotation::next());
+                let lt = meta.query_advice(b, Rotation::next());
+                // query selectors
+                let sel_lt = meta.query_selector(sel_lt);
+                let sel_le = meta.query_selector(sel_le);
+
+                let one = Expression::Constant(F::one());
+                let range = Expression::Constant(F::from_u128(1 << BITS));
+
+                // if either selector is enabled, the output is boolean and
+                // `a - b (- 1) + lt·2^64 = diff` is constrained.
+                let lt_diff = lhs - rhs + lt.clone() * range - diff;
+                vec![
+                    sel_lt.clone() * lt.clone() * (one.clone() - lt.clone()),
+                    sel_lt * lt_diff.clone(),
+                    sel_le.clone() * lt.clone() * (one.clone() - lt),
+                    sel_le * (lt_diff - one),
+                ]
+            },
+        );
+
+        // define the is-zero gate over the difference
+        meta.create_gate(
+            // gate name
+            "equality",
+            // gate logic
+            |meta| {
+                // query the operands from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the output and the inverse from the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                let inv = meta.query_advice(b, Rotation::next());
+                // query selectors
+                let sel_eq = meta.query_selector(sel_eq);
+                let sel_ne = meta.query_selector(sel_ne);
+
+                let one = Expression::Constant(F::one());
+                let d = lhs - rhs;
+
+                // if `sel_eq != 0`, then `out = 1 - d·inv` and `d·out = 0`.
+                // if `sel_ne != 0`, then `out = d·inv` and `d·(1 - out) = 0`.
+                vec![
+                    sel_eq.clone() * (d.clone() * inv.clone() + out.clone() - one.clone()),
+                    sel_eq * d.clone() * out.clone(),
+                    sel_ne.clone() * (d.clone() * inv - out.clone()),
+                    sel_ne * d * (one - out),
+                ]
+            },
+        );
+
+        // return config
+        CompareConfig {
+            a,
+            b,
+            sel_lt,
+            sel_le,
+            sel_eq,
+            sel_ne,
+            range_config,
+        }
+    }
+
+    /// Range checks both operands and assigns an ordering region under the
+    /// given selector, `offset` is subtracted from the difference.
+    fn ordering(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        selector: Selector,
+        offset: u64,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // constants are u64 by construction, everything else is range checked
+        let a = match a {
+            Input::Constant(_) => a,
+            _ => range_chip.range_check(layouter, a, BITS)?.into(),
+        };
+        let b = match b {
+            Input::Constant(_) => b,
+            _ => range_chip.range_check(layouter, b, BITS)?.into(),
+        };
+
+        self.ranged_ordering(layouter, a, b, selector, offset)
+    }
+
+    /// Assigns an ordering region under the given selector for operands that
+    /// are already range checked to u64, only the difference is range checked.
+    fn ranged_ordering(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        selector: Selector,
+        offset: u64,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the output and the difference
+        let lt = a.value().zip(b.value()).map(|(a, b)| {
+            match a.get_lower_128() < b.get_lower_128().saturating_add(offset as u128) {
+                true => F::one(),
+                false => F::zero(),
+            }
+        });
+        let diff = a
+            .value()
+            .zip(b.value())
+            .zip(lt)
+            .map(|((a, b), lt)| a - b - F::from(offset) + lt * F::from_u128(1 << BITS));
+
+        let (lt, diff) = layouter.assign_region(
+            // region name
+            || "ordering",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the ordering gate, set at region offset zero, it will
+                // constrain cells zero and one
+                selector.enable(&mut region, 0)?;
+
+                // assign the operands to offset zero
+                a.assign("lhs", &mut region, config.a, 0)?;
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // assign the difference and the output to offset one
+                let diff = region.assign_advice(|| "diff", config.a, 1, || diff)?;
+                let lt = region.assign_advice(|| "lt", config.b, 1, || lt)?;
+
+                Ok((Number(lt), Number(diff)))
+            },
+        )?;
+
+        // the difference must fit into u64
+        range_chip.range_check(layouter, diff.into(), BITS)?;
+
+        Ok(lt)
+    }
+
+    /// Less than on operands the caller already range checked to u64, e.g.
+    /// inputs used by several comparisons. Returns `a < b` without checking
+    /// the operands again.
+    pub fn ranged_lt(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Number<F>, Error> {
+        self.ranged_ordering(layouter, a, b, self.config().sel_lt, 0)
+    }
+
+    /// Assigns an equality region under the given selector, `eq` selects
+    /// whether the output is `a == b` or `a != b`.
+    fn equality(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        selector: Selector,
+        eq: bool,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // witness the inverse of the difference, zero has none
+        let d = a.value() - b.value();
+        let inv = d.map(|d| d.invert().unwrap_or(F::zero()));
+        let out = d.map(|d| match (d == F::zero()) == eq {
+            true => F::one(),
+            false => F::zero(),
+        });
+
+        layouter.assign_region(
+            // region name
+            || "equality",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the equality gate, set at region offset zero, it will
+                // constrain cells zero and one
+                selector.enable(&mut region, 0)?;
+
+                // assign the operands to offset zero
+                a.assign("lhs", &mut region, config.a, 0)?;
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // assign the inverse to offset one, column b
+                region.assign_advice(|| "inv", config.b, 1, || inv)?;
+
+                // mutate the region and return
+                region
+                    // assign the output to offset one, column a
+                    .assign_advice(|| "out", config.a, 1, || out)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
+
+/// Halo2 Chip implementation for CompareChip.
+impl<F: FieldExt> Chip<F> for CompareChip<F> {
+    /// Comparison configuration.
+    type Config = CompareConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Comparison instruction set implementation for CompareChip.
+impl<F: FieldExt> CompareInstructions<F> for CompareChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Less than instruction implementation.
+    fn lt(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.ordering(layouter, a, b, self.config().sel_lt, 0)
+    }
+
+    /// Less than or equal instruction implementation.
+    fn le(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.ordering(layouter, a, b, self.config().sel_le, 1)
+    }
+
+    /// Equality instruction implementation.
+    fn eq(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.equality(layouter, a, b, self.config().sel_eq, true)
+    }
+
+    /// Inequality instruction implementation.
+    fn ne(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.equality(layouter, a, b, self.config().sel_ne, false)
+    }
+}
@@ -0,0 +1,178 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Field division instruction set.
+pub trait DivInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Field division instruction.
+    /// Takes two inputs and returns `a·b^-1`, the divisor must not be zero.
+    fn div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Field division chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct DivConfig {
+    /// Advice column for `input_a` and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_b` and its inverse.
+    b: Column<Advice>,
+    /// Division selector.
+    sel_div: Selector,
+}
+
+/// Field division chip definition.
+///
+/// Witnesses the inverse of the divisor next to the output,
+///
+/// | a   | b     |
+/// |-----|-------|
+/// | a   | b     |
+/// | out | b^-1  |
+///
+/// constraining `b·b^-1 = 1`, so the divisor is not zero, and
+/// `out = a·b^-1`.
+pub struct DivChip<F: FieldExt> {
+    /// Field division configuration.
+    config: DivConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Field division chip implementation.
+impl<F: FieldExt> DivChip<F> {
+    /// Construct DivChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure DivChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_div = meta.selector();
+
+        // define the division gate
+        meta.create_gate(
+            // gate name
+            "div",
+            // gate logic
+            |meta| {
+                // query the operands from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the output and the inverse from the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                let inv = meta.query_advice(b, Rotation::next());
+                // query selector
+                let sel_div = meta.query_selector(sel_div);
+
+                let one = Expression::Constant(F::one());
+
+                // if `sel_div != 0`, then `rhs·inv = 1` and `lhs·inv = out`
+                vec![
+                    sel_div.clone() * (rhs * inv.clone() - one),
+                    sel_div * (lhs * inv - out),
+                ]
+            },
+        );
+
+        // return config
+        DivConfig { a, b, sel_div }
+    }
+}
+
+/// Halo2 Chip implementation for DivChip.
+impl<F: FieldExt> Chip<F> for DivChip<F> {
+    /// Field division configuration.
+    type Config = DivConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Field division instruction set implementation for DivChip.
+impl<F: FieldExt> DivInstructions<F> for DivChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Field division instruction implementation.
+    fn div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // witness the inverse, zero has none and fails the gate
+        let inv = b.value().map(|b| b.invert().unwrap_or(F::zero()));
+        let out = a.value() * inv;
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "div",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the division gate, set at region offset zero, it will
+                // constrain cells zero and one
+                config.sel_div.enable(&mut region, 0)?;
+
+                // assign the operands to offset zero
+                a.assign("lhs", &mut region, config.a, 0)?;
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // assign the inverse to offset one, column b
+                region.assign_advice(|| "inv", config.b, 1, || inv)?;
+
+                // mutate the region and return
+                region
+                    // assign the output to offset one, column a
+                    .assign_advice(|| "out", config.a, 1, || out)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
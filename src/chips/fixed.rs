@@ -0,0 +1,260 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    chips::{
+        arithmetic::{Input, Number},
+        compare::BITS,
+        mul,
+        plonk::{PlonkChip, PlonkConfig, PlonkInstructions},
+        range::{RangeChip, RangeConfig, RangeInstructions},
+    },
+    fixed::FixedPoint,
+};
+
+/// Fixed-point instruction set.
+/// Fixed-point numbers are u64 integers scaled by the format's scale.
+pub trait FixedPointInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Fixed-point multiplication instruction.
+    /// Takes two u64 inputs and returns `round(a·b / scale)`, rounded as the
+    /// format configures, which must fit into a u64.
+    fn fixed_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        format: FixedPoint,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Fixed-point chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct FixedPointConfig {
+    /// Advice column for the dividend, the remainder and its complement.
+    a: Column<Advice>,
+    /// Advice column for the quotient, the scale and the rounding offset.
+    b: Column<Advice>,
+    /// Rescale selector.
+    sel_rescale: Selector,
+    /// PLONK chip configuration, for products.
+    plonk_config: PlonkConfig,
+    /// Range check chip configuration, for the operands, quotient and
+    /// remainder.
+    range_config: RangeConfig,
+}
+
+/// Fixed-point chip definition.
+///
+/// A product `p` is rescaled by witnessing its quotient and remainder,
+///
+/// | a     | b      |
+/// |-------|--------|
+/// | p     | q      |
+/// | r     | scale  |
+/// | r'    | offset |
+///
+/// constraining `p + offset = q·scale + r` and `r' = scale - 1 - r`, with the
+/// scale and the rounding offset copied from constants. `q`, `r` and `r'` are
+/// range checked to u64, so `r < scale` and `q` is the rounded quotient.
+pub struct FixedPointChip<F: FieldExt> {
+    /// Fixed-point configuration.
+    config: FixedPointConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Fixed-point chip implementation.
+impl<F: FieldExt> FixedPointChip<F> {
+    /// Construct FixedPointChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure FixedPointChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        plonk_config: PlonkConfig,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_rescale = meta.selector();
+
+        // define the rescale gate
+        meta.create_gate(
+            // gate name
+            "rescale",
+            // gate logic
+            |meta| {
+                // query the dividend and the quotient from the current rotation
+                let p = meta.query_advice(a, Rotation::cur());
+                let q = meta.query_advice(b, Rotation::cur());
+                // query the remainder and the scale from the next rotation
+                let r = meta.query_advice(a, Rotation::next());
+                let scale = meta.query_advice(b, Rotation::next());
+                // query the complement and the offset from the last rotation
+                let complement = meta.query_advice(a, Rotation(2));
+                let offset = meta.query_advice(b, Rotation(2));
+                // query selector
+                let sel_rescale = meta.query_selector(sel_rescale);
+
+                let one = Expression::Constant(F::one());
+
+                // if `sel_rescale != 0`, then `p + offset = q·scale + r` and
+                // `r' = scale - 1 - r`
+                vec![
+                    sel_rescale.clone() * (p + offset - q * scale.clone() - r.clone()),
+                    sel_rescale * (complement - (scale - one - r)),
+                ]
+            },
+        );
+
+        // return config
+        FixedPointConfig {
+            a,
+            b,
+            sel_rescale,
+            plonk_config,
+            range_config,
+        }
+    }
+
+    /// Divides a u128 input by the scale of the format, rounding as it
+    /// configures. The quotient must fit into a u64.
+    pub fn rescale(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        p: Input<F>,
+        format: FixedPoint,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the quotient and the remainder, a product of two u64 fits
+        // into a u128
+        let scale = format.scale() as u128;
+        let offset = format.rounding.offset(format.scale()) as u128;
+        let dividend = p.value().map(|p| p.get_lower_128() + offset);
+        let q = dividend.map(|d| F::from_u128(d / scale));
+        let r = dividend.map(|d| F::from_u128(d % scale));
+        let complement = dividend.map(|d| F::from_u128(scale - 1 - d % scale));
+
+        let (q, r, complement) = layouter.assign_region(
+            // region name
+            || "rescale",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the rescale gate, set at region offset zero, it will
+                // constrain cells zero to two
+                config.sel_rescale.enable(&mut region, 0)?;
+
+                // assign the dividend and the quotient to offset zero
+                p.assign("p", &mut region, config.a, 0)?;
+                let q = region.assign_advice(|| "q", config.b, 0, || q)?;
+
+                // assign the remainder and the scale to offset one
+                let r = region.assign_advice(|| "r", config.a, 1, || r)?;
+                region.assign_advice_from_constant(|| "scale", config.b, 1, F::from_u128(scale))?;
+
+                // assign the complement and the offset to offset two
+                let complement = region.assign_advice(|| "r'", config.a, 2, || complement)?;
+                region.assign_advice_from_constant(
+                    || "offset",
+                    config.b,
+                    2,
+                    F::from_u128(offset),
+                )?;
+
+                Ok((Number(q), Number(r), Number(complement)))
+            },
+        )?;
+
+        // the remainder and its complement fit, so `r < scale`
+        range_chip.range_check(layouter, r.into(), BITS)?;
+        range_chip.range_check(layouter, complement.into(), BITS)?;
+
+        // the quotient must fit into u64
+        range_chip.range_check(layouter, q.into(), BITS)
+    }
+}
+
+/// Halo2 Chip implementation for FixedPointChip.
+impl<F: FieldExt> Chip<F> for FixedPointChip<F> {
+    /// Fixed-point configuration.
+    type Config = FixedPointConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Fixed-point instruction set implementation for FixedPointChip.
+impl<F: FieldExt> FixedPointInstructions<F> for FixedPointChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Fixed-point multiplication instruction implementation.
+    fn fixed_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        format: FixedPoint,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range and plonk chips
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+        let plonk_chip = PlonkChip::<F>::construct(config.plonk_config.clone(), ());
+
+        // constants are u64 by construction, everything else is range checked
+        // so the product cannot wrap around the field
+        let a = match a {
+            Input::Constant(_) => a,
+            _ => range_chip.range_check(layouter, a, BITS)?.into(),
+        };
+        let b = match b {
+            Input::Constant(_) => b,
+            _ => range_chip.range_check(layouter, b, BITS)?.into(),
+        };
+
+        // multiply, then divide the scale back out
+        let p = plonk_chip.apply(layouter, a, b, mul::coefficients())?;
+        self.rescale(layouter, p.into(), format)
+    }
+}
@@ -0,0 +1,174 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Fused multiply-add instruction set.
+pub trait FmaInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Fused multiply-add instruction.
+    /// Takes three inputs and returns `a * b + c`.
+    fn fma(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        c: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Fused multiply-add chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct FmaConfig {
+    /// Advice column for `input_a` and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_b` and `input_c`.
+    b: Column<Advice>,
+    /// Fused multiply-add selector.
+    sel_fma: Selector,
+}
+
+/// Fused multiply-add chip definition.
+pub struct FmaChip<F: FieldExt> {
+    /// Fused multiply-add configuration.
+    config: FmaConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Fused multiply-add chip implementation.
+impl<F: FieldExt> FmaChip<F> {
+    /// Construct FmaChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure FmaChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_fma = meta.selector();
+
+        // define the fused multiply-add gate
+        meta.create_gate(
+            // gate name
+            "fma",
+            // gate logic
+            |meta| {
+                // query advice from a on the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                // query advice from b on the current rotation
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query advice from b on the next rotation
+                let addend = meta.query_advice(b, Rotation::next());
+                // query advice from a on the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_fma = meta.query_selector(sel_fma);
+
+                // return iterable of `selector * (a * b + c - out)`
+                // if `sel_fma == 0`, then lhs, rhs, addend and out are not
+                // constrained.
+                // if `sel_fma != 0`, then `lhs * rhs + addend = out` is
+                // contrained.
+                vec![sel_fma * (lhs * rhs + addend - out)]
+            },
+        );
+
+        // return config
+        FmaConfig { a, b, sel_fma }
+    }
+}
+
+/// Halo2 Chip implementation for FmaChip.
+impl<F: FieldExt> Chip<F> for FmaChip<F> {
+    /// Fused multiply-add configuration.
+    type Config = FmaConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Fused multiply-add instruction set implementation for FmaChip.
+impl<F: FieldExt> FmaInstructions<F> for FmaChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Fused multiply-add instruction implementation.
+    fn fma(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        c: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "fma",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable fused multiply-add gate, set at region offset zero,
+                // it will constrain cells zero and one
+                config.sel_fma.enable(&mut region, 0)?;
+
+                // assign input a to offset zero, column a of the region
+                a.assign("lhs", &mut region, config.a, 0)?;
+
+                // assign input b to offset zero, column b of the region
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // assign input c to offset one, column b of the region
+                c.assign("addend", &mut region, config.b, 1)?;
+
+                // multiply the values in columns a and b at offset zero and
+                // add the value in column b at offset one
+                let out = a.value() * b.value() + c.value();
+
+                // mutate the region and return
+                region
+                    // assign the result as an advice value to column a,
+                    // offset one
+                    .assign_advice(|| "lhs * rhs + addend", config.a, 1, || out)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
@@ -0,0 +1,305 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    chips::{
+        arithmetic::{Input, Number},
+        compare::BITS,
+        range::{RangeChip, RangeConfig, RangeInstructions},
+    },
+    field, rational,
+};
+
+/// Greatest common divisor instruction set.
+pub trait GcdInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Greatest common divisor instruction.
+    /// Takes two u64 inputs and returns `gcd(a, b)`, `gcd(0, 0)` is `0`.
+    fn gcd(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Least common multiple instruction.
+    /// Takes two u64 inputs and returns `lcm(a, b)`, which must fit into a
+    /// u64.
+    fn lcm(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Greatest common divisor chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct GcdConfig {
+    /// Advice column for `input_a`, the divisor and the left parts.
+    a: Column<Advice>,
+    /// Advice column for `input_b`, the multiple and the right parts.
+    b: Column<Advice>,
+    /// Greatest common divisor selector.
+    sel_gcd: Selector,
+    /// Range check chip configuration, for the inputs and every witness.
+    range_config: RangeConfig,
+}
+
+/// Greatest common divisor chip definition.
+///
+/// Witnesses the divisor `g`, the cofactors `u = a/g` and `v = b/g` and the
+/// Bezout coefficients `x` and `y`,
+///
+/// | a        | b        |
+/// |----------|----------|
+/// | a        | b        |
+/// | g        | l        |
+/// | u        | v        |
+/// | x        | y        |
+/// | x + 2^63 | y + 2^63 |
+///
+/// constraining `a = g·u`, `b = g·v`, `a·x + b·y = g` and `l = a·v`. With
+/// `g`, `u` and `v` range checked to u64 and `x` and `y` to i64, nothing
+/// wraps around the field. So `g` divides both inputs, every common divisor
+/// divides `g`, and `g` is the greatest one. `l = a·b/g` is the least common
+/// multiple, range checked to u64 if it is the result.
+pub struct GcdChip<F: FieldExt> {
+    /// Greatest common divisor configuration.
+    config: GcdConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Greatest common divisor chip implementation.
+impl<F: FieldExt> GcdChip<F> {
+    /// Construct GcdChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure GcdChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_gcd = meta.selector();
+
+        // define the greatest common divisor gate
+        meta.create_gate(
+            // gate name
+            "gcd",
+            // gate logic
+            |meta| {
+                // query the inputs from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the divisor and the multiple from the next rotation
+                let g = meta.query_advice(a, Rotation::next());
+                let l = meta.query_advice(b, Rotation::next());
+                // query the cofactors and the Bezout coefficients
+                let u = meta.query_advice(a, Rotation(2));
+                let v = meta.query_advice(b, Rotation(2));
+                let x = meta.query_advice(a, Rotation(3));
+                let y = meta.query_advice(b, Rotation(3));
+                // query the offset coefficients to range check
+                let x_offset = meta.query_advice(a, Rotation(4));
+                let y_offset = meta.query_advice(b, Rotation(4));
+                // query selector
+                let sel_gcd = meta.query_selector(sel_gcd);
+
+                let half = Expression::Constant(F::from(1 << (BITS - 1)));
+
+                // if `sel_gcd != 0`, then `a = g·u`, `b = g·v`,
+                // `a·x + b·y = g`, `l = a·v` and the offset coefficients match
+                vec![
+                    sel_gcd.clone() * (lhs.clone() - g.clone() * u),
+                    sel_gcd.clone() * (rhs.clone() - g.clone() * v.clone()),
+                    sel_gcd.clone() * (lhs.clone() * x.clone() + rhs * y.clone() - g),
+                    sel_gcd.clone() * (l - lhs * v),
+                    sel_gcd.clone() * (x_offset - (x + half.clone())),
+                    sel_gcd * (y_offset - (y + half)),
+                ]
+            },
+        );
+
+        // return config
+        GcdConfig {
+            a,
+            b,
+            sel_gcd,
+            range_config,
+        }
+    }
+
+    /// Witnesses the divisor, the cofactors and the Bezout coefficients of
+    /// two inputs, and returns the divisor and the least common multiple.
+    /// The multiple is not range checked.
+    fn divisor(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // constants are u64 by construction, everything else is range checked
+        let a = match a {
+            Input::Constant(_) => a,
+            _ => range_chip.range_check(layouter, a, BITS)?.into(),
+        };
+        let b = match b {
+            Input::Constant(_) => b,
+            _ => range_chip.range_check(layouter, b, BITS)?.into(),
+        };
+
+        // witness the divisor, the cofactors and the coefficients, inputs
+        // past u64 are truncated and fail their range checks. The
+        // coefficients are at most half the inputs, so they fit into an i64
+        let inputs = a
+            .value()
+            .zip(b.value())
+            .map(|(a, b)| (a.get_lower_128() as u64, b.get_lower_128() as u64));
+        let witness = inputs.map(|(a, b)| {
+            let (g, x, y) = rational::extended_gcd(a as u128, b as u128);
+            let g = g as u64;
+            let (u, v) = match g {
+                0 => (0, 0),
+                g => (a / g, b / g),
+            };
+            (g, u, v, x, y, a as u128 * v as u128)
+        });
+        let half = F::from(1 << (BITS - 1));
+        let g = witness.map(|w| F::from(w.0));
+        let u = witness.map(|w| F::from(w.1));
+        let v = witness.map(|w| F::from(w.2));
+        let x = witness.map(|w| field::from_signed::<F>(w.3));
+        let y = witness.map(|w| field::from_signed::<F>(w.4));
+        let l = witness.map(|w| F::from_u128(w.5));
+
+        let (g, l, parts) = layouter.assign_region(
+            // region name
+            || "gcd",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the greatest common divisor gate, set at region
+                // offset zero, it will constrain cells zero to four
+                config.sel_gcd.enable(&mut region, 0)?;
+
+                // assign the inputs to offset zero
+                a.assign("a", &mut region, config.a, 0)?;
+                b.assign("b", &mut region, config.b, 0)?;
+
+                // assign the divisor and the multiple to offset one
+                let g = region.assign_advice(|| "g", config.a, 1, || g)?;
+                let l = region.assign_advice(|| "l", config.b, 1, || l)?;
+
+                // assign the cofactors and the coefficients to offsets two
+                // and three
+                let u = region.assign_advice(|| "u", config.a, 2, || u)?;
+                let v = region.assign_advice(|| "v", config.b, 2, || v)?;
+                region.assign_advice(|| "x", config.a, 3, || x)?;
+                region.assign_advice(|| "y", config.b, 3, || y)?;
+
+                // assign the offset coefficients to offset four
+                let x_offset =
+                    region.assign_advice(|| "x + 2^63", config.a, 4, || x + Value::known(half))?;
+                let y_offset =
+                    region.assign_advice(|| "y + 2^63", config.b, 4, || y + Value::known(half))?;
+
+                Ok((Number(g), Number(l), [u, v, x_offset, y_offset]))
+            },
+        )?;
+
+        // the cofactors are u64 and the coefficients i64, so no product
+        // wraps around the field
+        for part in parts {
+            range_chip.range_check(layouter, Number(part).into(), BITS)?;
+        }
+
+        // the divisor is u64
+        let g = range_chip.range_check(layouter, g.into(), BITS)?;
+
+        Ok((g, l))
+    }
+}
+
+/// Halo2 Chip implementation for GcdChip.
+impl<F: FieldExt> Chip<F> for GcdChip<F> {
+    /// Greatest common divisor configuration.
+    type Config = GcdConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Greatest common divisor instruction set implementation for GcdChip.
+impl<F: FieldExt> GcdInstructions<F> for GcdChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Greatest common divisor instruction implementation.
+    fn gcd(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.divisor(layouter, a, b).map(|(g, _)| g)
+    }
+
+    /// Least common multiple instruction implementation.
+    fn lcm(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // the least common multiple must fit into u64
+        let (_, l) = self.divisor(layouter, a, b)?;
+        range_chip.range_check(layouter, l.into(), BITS)
+    }
+}
@@ -0,0 +1,274 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Number of MiMC rounds.
+/// `ceil(log_5(p))` for the 255 bit pasta moduli.
+pub const ROUNDS: usize = 110;
+
+/// Hash instruction set.
+pub trait HashInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Hash instruction.
+    /// Takes a message and a key and returns the digest.
+    fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        message: Input<F>,
+        key: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Hash chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct HashConfig {
+    /// Advice column for the round state.
+    a: Column<Advice>,
+    /// Advice column for the key.
+    b: Column<Advice>,
+    /// Fixed column for the round constants.
+    round_constant: Column<Fixed>,
+    /// Round selector.
+    sel_round: Selector,
+    /// Final key addition selector.
+    sel_final: Selector,
+}
+
+/// Hash chip definition.
+///
+/// Implements a MiMC-style keyed permutation with an `x^5` s-box. Each round
+/// computes `s' = (s + k + c_i)^5`, the digest is `s_n + k`.
+///
+/// NOTE: this is for demonstration purposes, the parameters have not been
+/// audited and should not be relied upon for real-world commitments.
+pub struct HashChip<F: FieldExt> {
+    /// Hash configuration.
+    config: HashConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Hash chip implementation.
+impl<F: FieldExt> HashChip<F> {
+    /// Construct HashChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure HashChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get fixed column for the round constants
+        let round_constant = meta.fixed_column();
+
+        // get selectors
+        let sel_round = meta.selector();
+        let sel_final = meta.selector();
+
+        // define the round gate
+        meta.create_gate(
+            // gate name
+            "hash round",
+            // gate logic
+            |meta| {
+                // query the round state from a on the current rotation
+                let state = meta.query_advice(a, Rotation::cur());
+                // query the key from b on the current rotation
+                let key = meta.query_advice(b, Rotation::cur());
+                // query the round constant on the current rotation
+                let constant = meta.query_fixed(round_constant, Rotation::cur());
+                // query the next round state from a on the next rotation
+                let next_state = meta.query_advice(a, Rotation::next());
+                // query the key from b on the next rotation
+                let next_key = meta.query_advice(b, Rotation::next());
+                // query selector
+                let sel_round = meta.query_selector(sel_round);
+
+                // compute the s-box input
+                let x = state + key.clone() + constant;
+                let x2 = x.clone() * x.clone();
+                let x4 = x2.clone() * x2;
+
+                // if `sel_round != 0`, then `(state + key + c)^5 = next_state`
+                // is constrained and the key is carried to the next row.
+                vec![
+                    sel_round.clone() * (x4 * x - next_state),
+                    sel_round * (key - next_key),
+                ]
+            },
+        );
+
+        // define the final key addition gate
+        meta.create_gate(
+            // gate name
+            "hash final",
+            // gate logic
+            |meta| {
+                // query the last round state from a on the current rotation
+                let state = meta.query_advice(a, Rotation::cur());
+                // query the key from b on the current rotation
+                let key = meta.query_advice(b, Rotation::cur());
+                // query the digest from a on the next rotation
+                let digest = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_final = meta.query_selector(sel_final);
+
+                // if `sel_final != 0`, then `state + key = digest` is
+                // constrained.
+                vec![sel_final * (state + key - digest)]
+            },
+        );
+
+        // return config
+        HashConfig {
+            a,
+            b,
+            round_constant,
+            sel_round,
+            sel_final,
+        }
+    }
+}
+
+/// Halo2 Chip implementation for HashChip.
+impl<F: FieldExt> Chip<F> for HashChip<F> {
+    /// Hash configuration.
+    type Config = HashConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Hash instruction set implementation for HashChip.
+impl<F: FieldExt> HashInstructions<F> for HashChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Hash instruction implementation.
+    fn hash(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        message: Input<F>,
+        key: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // get round constants
+        let constants = round_constants::<F>();
+
+        // assign a region of gates and return
+        layouter.assign_region(
+            // region name
+            || "hash",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // assign the message to offset zero, column a of the region
+                let mut state = message.assign("message", &mut region, config.a, 0)?;
+
+                // assign the key to offset zero, column b of the region
+                let mut k = key.assign("key", &mut region, config.b, 0)?;
+
+                for (round, constant) in constants.iter().enumerate() {
+                    // enable the round gate, it constrains rows `round` and
+                    // `round + 1`
+                    config.sel_round.enable(&mut region, round)?;
+
+                    // assign the round constant
+                    region.assign_fixed(
+                        || "round constant",
+                        config.round_constant,
+                        round,
+                        || Value::known(*constant),
+                    )?;
+
+                    // compute the next round state
+                    let next = (state.value().copied() + k.value() + Value::known(*constant))
+                        .map(|x| x.pow(&[5, 0, 0, 0]));
+
+                    // assign the next state and carry the key
+                    state = region.assign_advice(|| "round", config.a, round + 1, || next)?;
+                    k = region.assign_advice(
+                        || "key",
+                        config.b,
+                        round + 1,
+                        || k.value().copied(),
+                    )?;
+                }
+
+                // enable the final key addition gate
+                config.sel_final.enable(&mut region, ROUNDS)?;
+
+                // add the key to the last round state
+                let digest = state.value().copied() + k.value();
+
+                // mutate the region and return
+                region
+                    // assign the digest into column a, offset `ROUNDS + 1`
+                    .assign_advice(|| "digest", config.a, ROUNDS + 1, || digest)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
+
+/// Returns the round constants.
+/// Constants are derived as `c_0 = 0` and `c_i = (c_{i-1} + i)^3`.
+pub fn round_constants<F: FieldExt>() -> Vec<F> {
+    let mut constants = Vec::with_capacity(ROUNDS);
+    let mut constant = F::zero();
+
+    for round in 0..ROUNDS {
+        constants.push(constant);
+        constant = (constant + F::from(round as u64 + 1)).pow(&[3, 0, 0, 0]);
+    }
+
+    constants
+}
+
+/// Computes the hash natively, outside of the circuit.
+/// Mirrors `HashInstructions::hash`.
+pub fn hash<F: FieldExt>(message: F, key: F) -> F {
+    let state = round_constants::<F>()
+        .into_iter()
+        .fold(message, |state, constant| {
+            (state + key + constant).pow(&[5, 0, 0, 0])
+        });
+
+    state + key
+}
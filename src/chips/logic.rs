@@ -0,0 +1,256 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Boolean logic instruction set.
+/// Inputs must be booleans, `0` or `1`, which each instruction constrains.
+pub trait LogicInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Logical and instruction.
+    /// Takes two booleans and returns `a && b`.
+    fn and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Logical or instruction.
+    /// Takes two booleans and returns `a || b`.
+    fn or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Logical not instruction.
+    /// Takes a boolean and returns `!a`.
+    fn not(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error>;
+}
+
+/// Boolean logic chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct LogicConfig {
+    /// Advice column for `input_a` and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_b`.
+    b: Column<Advice>,
+    /// Logical and selector.
+    sel_and: Selector,
+    /// Logical or selector.
+    sel_or: Selector,
+    /// Logical not selector.
+    sel_not: Selector,
+}
+
+/// Boolean logic chip definition.
+pub struct LogicChip<F: FieldExt> {
+    /// Boolean logic configuration.
+    config: LogicConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Boolean logic chip implementation.
+impl<F: FieldExt> LogicChip<F> {
+    /// Construct LogicChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure LogicChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_and = meta.selector();
+        let sel_or = meta.selector();
+        let sel_not = meta.selector();
+
+        // define the binary boolean logic gate
+        meta.create_gate(
+            // gate name
+            "logic",
+            // gate logic
+            |meta| {
+                // query advice value from a on the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                // query advice value from b on the current rotation
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query advice value from a on the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                // query selectors
+                let sel_and = meta.query_selector(sel_and);
+                let sel_or = meta.query_selector(sel_or);
+
+                let one = Expression::Constant(F::one());
+                let lhs_bool = lhs.clone() * (one.clone() - lhs.clone());
+                let rhs_bool = rhs.clone() * (one - rhs.clone());
+
+                // every enabled selector constrains its inputs to booleans.
+                // if `sel_and != 0`, then `lhs·rhs = out`.
+                // if `sel_or != 0`, then `lhs + rhs - lhs·rhs = out`.
+                vec![
+                    sel_and.clone() * lhs_bool.clone(),
+                    sel_and.clone() * rhs_bool.clone(),
+                    sel_and * (lhs.clone() * rhs.clone() - out.clone()),
+                    sel_or.clone() * lhs_bool,
+                    sel_or.clone() * rhs_bool,
+                    sel_or * (lhs.clone() + rhs.clone() - lhs * rhs - out),
+                ]
+            },
+        );
+
+        // define the unary negation gate, it does not query column b
+        meta.create_gate(
+            // gate name
+            "not",
+            // gate logic
+            |meta| {
+                // query advice value from a on the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                // query advice value from a on the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_not = meta.query_selector(sel_not);
+
+                let one = Expression::Constant(F::one());
+
+                // if `sel_not != 0`, then the input is boolean and
+                // `1 - lhs = out`.
+                vec![
+                    sel_not.clone() * lhs.clone() * (one.clone() - lhs.clone()),
+                    sel_not * (one - lhs - out),
+                ]
+            },
+        );
+
+        // return config
+        LogicConfig {
+            a,
+            b,
+            sel_and,
+            sel_or,
+            sel_not,
+        }
+    }
+
+    /// Assigns a logic region under the given selector and returns the
+    /// output, `b` is left unassigned for unary instructions.
+    fn apply(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Option<Input<F>>,
+        selector: Selector,
+        out: Value<F>,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "logic",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the logic gate, set at region offset zero, it will
+                // constrain cells zero and one
+                selector.enable(&mut region, 0)?;
+
+                // assign input a to offset zero, column a of the region
+                a.assign("lhs", &mut region, config.a, 0)?;
+
+                // assign input b to offset zero, column b of the region
+                if let Some(b) = &b {
+                    b.assign("rhs", &mut region, config.b, 0)?;
+                }
+
+                // mutate the region and return
+                region
+                    // assign the output as an advice value to column a,
+                    // offset one
+                    .assign_advice(|| "out", config.a, 1, || out)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
+
+/// Halo2 Chip implementation for LogicChip.
+impl<F: FieldExt> Chip<F> for LogicChip<F> {
+    /// Boolean logic configuration.
+    type Config = LogicConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Boolean logic instruction set implementation for LogicChip.
+impl<F: FieldExt> LogicInstructions<F> for LogicChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Logical and instruction implementation.
+    fn and(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        let out = a.value() * b.value();
+        self.apply(layouter, a, Some(b), self.config().sel_and, out)
+    }
+
+    /// Logical or instruction implementation.
+    fn or(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        let out = a.value() + b.value() - a.value() * b.value();
+        self.apply(layouter, a, Some(b), self.config().sel_or, out)
+    }
+
+    /// Logical not instruction implementation.
+    fn not(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        let out = Value::known(F::one()) - a.value();
+        self.apply(layouter, a, None, self.config().sel_not, out)
+    }
+}
@@ -0,0 +1,185 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter},
+    plonk::Error,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    compare::{CompareChip, CompareConfig, BITS},
+    range::{RangeChip, RangeConfig, RangeInstructions},
+    select::{SelectChip, SelectConfig, SelectInstructions},
+};
+
+/// Minimum and maximum instruction set.
+/// Each instruction takes at least two u64 inputs.
+pub trait MinMaxInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Minimum instruction.
+    /// Takes u64 inputs and returns the smallest.
+    fn min(&self, layouter: &mut impl Layouter<F>, inputs: &[Input<F>])
+        -> Result<Self::Num, Error>;
+
+    /// Maximum instruction.
+    /// Takes u64 inputs and returns the largest.
+    fn max(&self, layouter: &mut impl Layouter<F>, inputs: &[Input<F>])
+        -> Result<Self::Num, Error>;
+}
+
+/// Minimum and maximum chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct MinMaxConfig {
+    /// Comparison chip configuration, for ordering each pair.
+    compare_config: CompareConfig,
+    /// Conditional select chip configuration, for keeping the extremum.
+    select_config: SelectConfig,
+    /// Range check chip configuration, for the inputs.
+    range_config: RangeConfig,
+}
+
+/// Minimum and maximum chip definition.
+///
+/// The chip has no gate of its own. Every input but a constant is range
+/// checked to u64 once, so each can be copied into both the comparison and
+/// the select. The inputs are then folded from the left, comparing the
+/// running extremum `e` with the next input `x` as `lt = e < x` and selecting
+/// `lt ? e : x` for the minimum and `lt ? x : e` for the maximum. Only the
+/// difference of each comparison is range checked, as `e` is always one of
+/// the checked inputs.
+pub struct MinMaxChip<F: FieldExt> {
+    /// Minimum and maximum configuration.
+    config: MinMaxConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Minimum and maximum chip implementation.
+impl<F: FieldExt> MinMaxChip<F> {
+    /// Construct MinMaxChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure MinMaxChip and return the Config.
+    pub fn configure(
+        compare_config: CompareConfig,
+        select_config: SelectConfig,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // the minimum and maximum chip only composes the gates of other chips
+        MinMaxConfig {
+            compare_config,
+            select_config,
+            range_config,
+        }
+    }
+
+    /// Folds the inputs into their extremum, `max` selects whether it is the
+    /// maximum or the minimum.
+    fn extremum(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Input<F>],
+        max: bool,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // a lone input has no extremum to prove
+        if inputs.len() < 2 {
+            return Err(Error::Synthesis);
+        }
+
+        // construct the composed chips
+        let compare_chip = CompareChip::<F>::construct(config.compare_config.clone(), ());
+        let select_chip = SelectChip::<F>::construct(config.select_config.clone(), ());
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // inputs are range checked once, private inputs are witnessed into
+        // their range check and constants are u64 by construction
+        let mut inputs = inputs
+            .iter()
+            .map(|input| match input {
+                Input::Constant(_) => Ok(input.clone()),
+                _ => range_chip
+                    .range_check(layouter, input.clone(), BITS)
+                    .map(Input::from),
+            })
+            .collect::<Result<Vec<Input<F>>, Error>>()?
+            .into_iter();
+
+        // keep the smaller or larger of the running extremum and each input
+        let first = inputs.next().ok_or(Error::Synthesis)?;
+        let extremum = inputs.try_fold(first, |e, x| {
+            let lt = compare_chip.ranged_lt(layouter, e.clone(), x.clone())?;
+            let (a, b) = match max {
+                true => (x, e),
+                false => (e, x),
+            };
+            select_chip
+                .select(layouter, lt.into(), a, b)
+                .map(Input::from)
+        })?;
+
+        // the last select output is the extremum
+        match extremum {
+            Input::Number(extremum) => Ok(extremum),
+            _ => Err(Error::Synthesis),
+        }
+    }
+}
+
+/// Halo2 Chip implementation for MinMaxChip.
+impl<F: FieldExt> Chip<F> for MinMaxChip<F> {
+    /// Minimum and maximum configuration.
+    type Config = MinMaxConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Minimum and maximum instruction set implementation for MinMaxChip.
+impl<F: FieldExt> MinMaxInstructions<F> for MinMaxChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Minimum instruction implementation.
+    fn min(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Input<F>],
+    ) -> Result<Self::Num, Error> {
+        self.extremum(layouter, inputs, false)
+    }
+
+    /// Maximum instruction implementation.
+    fn max(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        inputs: &[Input<F>],
+    ) -> Result<Self::Num, Error> {
+        self.extremum(layouter, inputs, true)
+    }
+}
@@ -1,4 +1,25 @@
+pub mod abs;
 pub mod add;
 pub mod arithmetic;
+pub mod bigint;
+pub mod bitwise;
+pub mod compare;
+pub mod div;
+pub mod fixed;
+pub mod fma;
+pub mod gcd;
+pub mod hash;
+pub mod logic;
+pub mod min_max;
+pub mod modular;
 pub mod mul;
+pub mod plonk;
+pub mod pow;
+pub mod private_pow;
+pub mod range;
+pub mod rational;
+pub mod saturating;
+pub mod select;
+pub mod sqrt;
 pub mod sub;
+pub mod wrapping;
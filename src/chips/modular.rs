@@ -0,0 +1,396 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    chips::{
+        arithmetic::{Input, Number},
+        compare::BITS,
+        range::{RangeChip, RangeConfig, RangeInstructions},
+    },
+    zk_calculator::Operand,
+};
+
+/// Modular arithmetic instruction set.
+/// The modulus is a public u64 of at least one. Every instruction except
+/// `reduce_mod` expects inputs that are already reduced below the modulus.
+pub trait ModularInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Modular reduction instruction.
+    /// Takes an input below `m·2^64`, e.g. any u64, and returns `a mod m`.
+    fn reduce_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error>;
+
+    /// Modular addition instruction.
+    /// Takes two reduced inputs and returns `(a + b) mod m`.
+    fn add_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error>;
+
+    /// Modular subtraction instruction.
+    /// Takes two reduced inputs and returns `(a - b) mod m`, never negative.
+    fn sub_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error>;
+
+    /// Modular multiplication instruction.
+    /// Takes two reduced inputs and returns `(a·b) mod m`.
+    fn mul_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error>;
+
+    /// Modular exponentiation instruction.
+    /// Takes a reduced input and a public exponent and returns
+    /// `a^exponent mod m`, reducing after every step.
+    fn pow_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Operand,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Modular arithmetic chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct ModularConfig {
+    /// Advice column for `input_a`, the quotient and the remainder.
+    a: Column<Advice>,
+    /// Advice column for `input_b`, the modulus and the remainder's
+    /// complement.
+    b: Column<Advice>,
+    /// Reduction selector, reduces `a`.
+    sel_reduce: Selector,
+    /// Addition selector, reduces `a + b`.
+    sel_add: Selector,
+    /// Subtraction selector, reduces `a - b + m`.
+    sel_sub: Selector,
+    /// Multiplication selector, reduces `a·b`.
+    sel_mul: Selector,
+    /// Range check chip configuration, for the quotient and the remainders.
+    range_config: RangeConfig,
+}
+
+/// Modular arithmetic chip definition.
+///
+/// Computes the intermediate result `v` of the operation, `a`, `a + b`,
+/// `a - b + m` or `a·b`, and witnesses its quotient `q` and remainder `r`,
+///
+/// | a  | b  |
+/// |----|----|
+/// | a  | b  |
+/// | q  | m  |
+/// | r  | r' |
+///
+/// constraining `v = q·m + r` and `r' = m - 1 - r`, with `m` copied from the
+/// constant column. With `q`, `r` and `r'` range checked to u64, nothing
+/// wraps around the field and `r < m`, so `r` is `v mod m`. Reduced inputs
+/// keep `v` below `m^2`, so the quotient fits.
+pub struct ModularChip<F: FieldExt> {
+    /// Modular arithmetic configuration.
+    config: ModularConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Modular arithmetic chip implementation.
+impl<F: FieldExt> ModularChip<F> {
+    /// Construct ModularChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure ModularChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_reduce = meta.selector();
+        let sel_add = meta.selector();
+        let sel_sub = meta.selector();
+        let sel_mul = meta.selector();
+
+        // define the modular gate
+        meta.create_gate(
+            // gate name
+            "modular",
+            // gate logic
+            |meta| {
+                // query the inputs from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the quotient and the modulus from the next rotation
+                let q = meta.query_advice(a, Rotation::next());
+                let m = meta.query_advice(b, Rotation::next());
+                // query the remainder and its complement
+                let r = meta.query_advice(a, Rotation(2));
+                let complement = meta.query_advice(b, Rotation(2));
+                // query selectors
+                let sel_reduce = meta.query_selector(sel_reduce);
+                let sel_add = meta.query_selector(sel_add);
+                let sel_sub = meta.query_selector(sel_sub);
+                let sel_mul = meta.query_selector(sel_mul);
+
+                let one = Expression::Constant(F::one());
+                let split = q * m.clone() + r.clone();
+                let complement = m.clone() - one - r - complement;
+
+                // if a selector is enabled, the intermediate result of its
+                // operation equals `q·m + r` and the complement is `m - 1 - r`
+                vec![
+                    sel_reduce.clone() * (lhs.clone() - split.clone()),
+                    sel_reduce * complement.clone(),
+                    sel_add.clone() * (lhs.clone() + rhs.clone() - split.clone()),
+                    sel_add * complement.clone(),
+                    sel_sub.clone() * (lhs.clone() - rhs.clone() + m - split.clone()),
+                    sel_sub * complement.clone(),
+                    sel_mul.clone() * (lhs * rhs - split),
+                    sel_mul * complement,
+                ]
+            },
+        );
+
+        // return config
+        ModularConfig {
+            a,
+            b,
+            sel_reduce,
+            sel_add,
+            sel_sub,
+            sel_mul,
+            range_config,
+        }
+    }
+
+    /// Reduces the intermediate result `value` of an operation, enabled by
+    /// the selector, modulo `m`. `b` is `None` for a plain reduction.
+    fn reduce(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        selector: Selector,
+        (a, b): (Input<F>, Option<Input<F>>),
+        value: Value<F>,
+        modulus: Operand,
+    ) -> Result<Number<F>, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the quotient, the remainder and its complement, a result
+        // past u128 is truncated and fails the gate
+        let m = F::from(modulus);
+        let split = value.map(|v| {
+            let v = v.get_lower_128();
+            let (q, r) = (v / modulus as u128, (v % modulus as u128) as Operand);
+            (F::from_u128(q), F::from(r), F::from(modulus - 1 - r))
+        });
+
+        let (r, parts) = layouter.assign_region(
+            // region name
+            || "modular",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the operation's gate, set at region offset zero, it
+                // will constrain cells zero to two
+                selector.enable(&mut region, 0)?;
+
+                // assign the inputs to offset zero
+                a.assign("a", &mut region, config.a, 0)?;
+                // a plain reduction has no `b`, the cell is queried anyway
+                match &b {
+                    Some(b) => b.assign("b", &mut region, config.b, 0)?,
+                    None => {
+                        region.assign_advice(|| "b", config.b, 0, || Value::known(F::zero()))?
+                    }
+                };
+
+                // assign the quotient and the public modulus to offset one
+                let q = region.assign_advice(|| "q", config.a, 1, || split.map(|s| s.0))?;
+                region.assign_advice_from_constant(|| "m", config.b, 1, m)?;
+
+                // assign the remainder and its complement to offset two
+                let r = region.assign_advice(|| "r", config.a, 2, || split.map(|s| s.1))?;
+                let complement =
+                    region.assign_advice(|| "r'", config.b, 2, || split.map(|s| s.2))?;
+
+                Ok((r, [q, complement]))
+            },
+        )?;
+
+        // the quotient and the complement are u64, so `q·m + r` does not
+        // wrap and `r < m`
+        for part in parts {
+            range_chip.range_check(layouter, Number(part).into(), BITS)?;
+        }
+
+        // the remainder is a u64
+        range_chip.range_check(layouter, Number(r).into(), BITS)
+    }
+}
+
+/// Halo2 Chip implementation for ModularChip.
+impl<F: FieldExt> Chip<F> for ModularChip<F> {
+    /// Modular arithmetic configuration.
+    type Config = ModularConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Modular arithmetic instruction set implementation for ModularChip.
+impl<F: FieldExt> ModularInstructions<F> for ModularChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Modular reduction instruction implementation.
+    fn reduce_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        let value = a.value();
+        self.reduce(
+            layouter,
+            self.config().sel_reduce,
+            (a, None),
+            value,
+            modulus,
+        )
+    }
+
+    /// Modular addition instruction implementation.
+    fn add_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        let value = a.value() + b.value();
+        self.reduce(
+            layouter,
+            self.config().sel_add,
+            (a, Some(b)),
+            value,
+            modulus,
+        )
+    }
+
+    /// Modular subtraction instruction implementation.
+    fn sub_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        let value = a.value() - b.value() + Value::known(F::from(modulus));
+        self.reduce(
+            layouter,
+            self.config().sel_sub,
+            (a, Some(b)),
+            value,
+            modulus,
+        )
+    }
+
+    /// Modular multiplication instruction implementation.
+    fn mul_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        let value = a.value() * b.value();
+        self.reduce(
+            layouter,
+            self.config().sel_mul,
+            (a, Some(b)),
+            value,
+            modulus,
+        )
+    }
+
+    /// Modular exponentiation instruction implementation.
+    fn pow_mod(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Operand,
+        modulus: Operand,
+    ) -> Result<Self::Num, Error> {
+        // `a^0 = 1`, reduced since the modulus may be one
+        if exponent == 0 {
+            return self.reduce_mod(layouter, Input::Constant(F::one()), modulus);
+        }
+
+        // square and multiply from the most significant bit, which is the
+        // base itself
+        let bits = 64 - exponent.leading_zeros() as usize;
+        let mut acc = a.clone();
+        for bit in (0..bits - 1).rev() {
+            acc = self.mul_mod(layouter, acc.clone(), acc, modulus)?.into();
+            if exponent >> bit & 1 == 1 {
+                acc = self.mul_mod(layouter, acc, a.clone(), modulus)?.into();
+            }
+        }
+
+        // a lone base that is not yet a number is loaded by a reduction
+        match acc {
+            Input::Number(acc) => Ok(acc),
+            acc => self.reduce_mod(layouter, acc, modulus),
+        }
+    }
+}
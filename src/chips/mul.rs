@@ -1,15 +1,10 @@
-use std::marker::PhantomData;
-
 use halo2_proofs::{
     arithmetic::FieldExt,
-    circuit::{Chip, Layouter, Region},
-    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
-    poly::Rotation,
+    circuit::{Chip, Layouter},
+    plonk::Error,
 };
 
-// we import `Number` from the top level chip to avoid redeclaring the same
-// `Number` type for each operator chip.
-use crate::chips::arithmetic::Number;
+use crate::chips::{arithmetic::Input, plonk::Coefficients};
 
 /// Multiplication instruction set.
 pub trait MulInstructions<F: FieldExt>: Chip<F> {
@@ -17,148 +12,49 @@ pub trait MulInstructions<F: FieldExt>: Chip<F> {
     type Num;
 
     /// Multiplication instruction.
-    /// Takes two inputs and returns the sum.
+    /// Takes two inputs and returns the product.
     fn mul(
         &self,
         layouter: &mut impl Layouter<F>,
-        a: Self::Num,
-        b: Self::Num,
+        a: Input<F>,
+        b: Input<F>,
     ) -> Result<Self::Num, Error>;
 }
 
-/// Multiplication chip configuration.
-/// Derived during `Chip::configure`.
-#[derive(Clone, Debug)]
-pub struct MulConfig {
-    /// Advice column for `input_a` and `output`.
-    a: Column<Advice>,
-    /// Advice column for `input_b`.
-    b: Column<Advice>,
-    /// Multiplication selector.
-    sel_mul: Selector,
-}
-
-/// Multiplication chip definition.
-pub struct MulChip<F: FieldExt> {
-    /// Multiplication configuration.
-    config: MulConfig,
-    /// Placeholder data.
-    _marker: PhantomData<F>,
-}
-
-/// Multiplication chip implementation.
-impl<F: FieldExt> MulChip<F> {
-    /// Construct MulChip and return.
-    pub fn construct(
-        config: <Self as Chip<F>>::Config,
-        _loaded: <Self as Chip<F>>::Loaded,
-    ) -> Self {
-        Self {
-            config,
-            _marker: PhantomData,
-        }
-    }
-
-    /// Configure MulChip and return the Config.
-    pub fn configure(
-        meta: &mut ConstraintSystem<F>,
-        a: Column<Advice>,
-        b: Column<Advice>,
-    ) -> <Self as Chip<F>>::Config {
-        // enable equality on columns
-        meta.enable_equality(a);
-        meta.enable_equality(b);
-
-        // get selector
-        let sel_mul = meta.selector();
-
-        // define the multiplication gate
-        meta.create_gate(
-            // gate name
-            "mul",
-            // gate logic
-            |meta| {
-                // query advice from a on the current rotation
-                let lhs = meta.query_advice(a, Rotation::cur());
-                // query advice from b on the current rotation
-                let rhs = meta.query_advice(b, Rotation::cur());
-                // query advice from a on the next rotation
-                let out = meta.query_advice(a, Rotation::next());
-                // query selector
-                let sel_mul = meta.query_selector(sel_mul);
-
-                // return iterable of `selector * (a * b - c)`
-                // if `sel_mul == 0`, then lhs, rhs and out are not constrained.
-                // if `sel_mul != 0`, then `lhs * rhs = out` is contrained.
-                vec![sel_mul * (lhs * rhs - out)]
-            },
-        );
+/// Multiplication-by-constant instruction set.
+pub trait MulConstantInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
 
-        // return config
-        MulConfig { a, b, sel_mul }
-    }
+    /// Multiplication-by-constant instruction.
+    /// Takes one input and a constant and returns the product.
+    fn mul_constant(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error>;
 }
 
-/// Halo2 Chip implementation for MulChip.
-impl<F: FieldExt> Chip<F> for MulChip<F> {
-    /// Multiplication configuration.
-    type Config = MulConfig;
-    /// Loaded data.
-    type Loaded = ();
-
-    /// Returns a configuration reference.
-    fn config(&self) -> &Self::Config {
-        &self.config
-    }
-
-    /// Returns a configuration reference.
-    fn loaded(&self) -> &Self::Loaded {
-        &()
+/// Multiplication gate coefficients, `a·b - c = 0`.
+pub fn coefficients<F: FieldExt>() -> Coefficients<F> {
+    Coefficients {
+        q_l: F::zero(),
+        q_r: F::zero(),
+        q_m: F::one(),
+        q_o: -F::one(),
+        q_c: F::zero(),
     }
 }
 
-/// Multiplication instruction set implementation for Mulchip.
-impl<F: FieldExt> MulInstructions<F> for MulChip<F> {
-    /// Numeric type definition.
-    type Num = Number<F>;
-
-    /// Multiplication instruction implementation.
-    fn mul(
-        &self,
-        layouter: &mut impl Layouter<F>,
-        a: Self::Num,
-        b: Self::Num,
-    ) -> Result<Self::Num, Error> {
-        // get config
-        let config = self.config();
-
-        // assign region and return
-        layouter.assign_region(
-            // region name
-            || "mul",
-            // assignment
-            |mut region: Region<'_, F>| {
-                // enable multiplication gate, set at region offset zero,
-                // it will constrain cells zero and one
-                config.sel_mul.enable(&mut region, 0)?;
-
-                // copy advice value a to offset zero, column a of the region
-                a.0.copy_advice(|| "lhs", &mut region, config.a, 0)?;
-
-                // copy advice value b to offset zero, column b of the region
-                b.0.copy_advice(|| "rhs", &mut region, config.b, 0)?;
-
-                // multiply the two values in columns a and b at offset zero
-                let c = a.0.value().copied() * b.0.value();
-
-                // mutate the region and return
-                region
-                    // assign the product c as an advice value to column a,
-                    // offset one
-                    .assign_advice(|| "lhs * rhs", config.a, 1, || c)
-                    // map the result to `Number`
-                    .map(Number)
-            },
-        )
+/// Multiplication-by-constant gate coefficients, `constant·a - c = 0`.
+/// The constant is baked into the fixed left input coefficient column.
+pub fn constant_coefficients<F: FieldExt>(constant: F) -> Coefficients<F> {
+    Coefficients {
+        q_l: constant,
+        q_r: F::zero(),
+        q_m: F::zero(),
+        q_o: -F::one(),
+        q_c: F::zero(),
     }
 }
@@ -0,0 +1,300 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Coefficients of the standard PLONK gate
+/// `q_l·a + q_r·b + q_m·a·b + q_o·c + q_c = 0`.
+/// Each arithmetic operation is a choice of coefficients.
+#[derive(Clone, Copy, Debug)]
+pub struct Coefficients<F: FieldExt> {
+    /// Left input coefficient.
+    pub q_l: F,
+    /// Right input coefficient.
+    pub q_r: F,
+    /// Multiplication coefficient.
+    pub q_m: F,
+    /// Output coefficient, must be non-zero.
+    pub q_o: F,
+    /// Constant coefficient.
+    pub q_c: F,
+}
+
+/// Coefficients implementation.
+impl<F: FieldExt> Coefficients<F> {
+    /// Computes the output `c` such that the gate is satisfied.
+    /// Returns `None` if `q_o` is zero, the gate then does not determine `c`.
+    pub fn output(&self, a: Value<F>, b: Value<F>) -> Option<Value<F>> {
+        let q_o_inv = Option::<F>::from(self.q_o.invert())?;
+
+        Some(
+            a.zip(b).map(|(a, b)| {
+                -(self.q_l * a + self.q_r * b + self.q_m * a * b + self.q_c) * q_o_inv
+            }),
+        )
+    }
+}
+
+/// PLONK instruction set.
+pub trait PlonkInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// PLONK gate instruction.
+    /// Takes two inputs and returns the output satisfying the gate under the
+    /// given coefficients.
+    fn apply(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        coefficients: Coefficients<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Unary PLONK gate instruction.
+    /// Takes one input and returns the output satisfying the gate under the
+    /// given coefficients, which must not involve the right input.
+    fn apply_unary(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        coefficients: Coefficients<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// PLONK chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct PlonkConfig {
+    /// Advice column for `input_a` and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_b`.
+    b: Column<Advice>,
+    /// Fixed column for the left input coefficient.
+    q_l: Column<Fixed>,
+    /// Fixed column for the right input coefficient.
+    q_r: Column<Fixed>,
+    /// Fixed column for the multiplication coefficient.
+    q_m: Column<Fixed>,
+    /// Fixed column for the output coefficient.
+    q_o: Column<Fixed>,
+    /// Fixed column for the constant coefficient.
+    q_c: Column<Fixed>,
+}
+
+/// PLONK chip definition.
+pub struct PlonkChip<F: FieldExt> {
+    /// PLONK configuration.
+    config: PlonkConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// PLONK chip implementation.
+impl<F: FieldExt> PlonkChip<F> {
+    /// Construct PlonkChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure PlonkChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get fixed coefficient columns, the coefficients double as the
+        // selector, so rows with all-zero coefficients are not constrained
+        let q_l = meta.fixed_column();
+        let q_r = meta.fixed_column();
+        let q_m = meta.fixed_column();
+        let q_o = meta.fixed_column();
+        let q_c = meta.fixed_column();
+
+        // define the standard PLONK gate
+        meta.create_gate(
+            // gate name
+            "plonk",
+            // gate logic
+            |meta| {
+                // query advice value from a on the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                // query advice value from b on the current rotation
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query advice value from a on the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+
+                // query coefficients on the current rotation
+                let q_l = meta.query_fixed(q_l, Rotation::cur());
+                let q_r = meta.query_fixed(q_r, Rotation::cur());
+                let q_m = meta.query_fixed(q_m, Rotation::cur());
+                let q_o = meta.query_fixed(q_o, Rotation::cur());
+                let q_c = meta.query_fixed(q_c, Rotation::cur());
+
+                // return an iterable of
+                // `q_l·lhs + q_r·rhs + q_m·lhs·rhs + q_o·out + q_c`
+                vec![q_l * lhs.clone() + q_r * rhs.clone() + q_m * lhs * rhs + q_o * out + q_c]
+            },
+        );
+
+        // return config
+        PlonkConfig {
+            a,
+            b,
+            q_l,
+            q_r,
+            q_m,
+            q_o,
+            q_c,
+        }
+    }
+}
+
+/// Halo2 Chip implementation for PlonkChip.
+impl<F: FieldExt> Chip<F> for PlonkChip<F> {
+    /// PLONK configuration.
+    type Config = PlonkConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// PLONK instruction set implementation for PlonkChip.
+impl<F: FieldExt> PlonkInstructions<F> for PlonkChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// PLONK gate instruction implementation.
+    fn apply(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        coefficients: Coefficients<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign a region of gates and return
+        layouter.assign_region(
+            // region name
+            || "plonk",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // assign the coefficients at offset zero, this enables the
+                // gate on cells zero and one
+                for (column, coefficient) in [
+                    (config.q_l, coefficients.q_l),
+                    (config.q_r, coefficients.q_r),
+                    (config.q_m, coefficients.q_m),
+                    (config.q_o, coefficients.q_o),
+                    (config.q_c, coefficients.q_c),
+                ] {
+                    region.assign_fixed(
+                        || "coefficient",
+                        column,
+                        0,
+                        || Value::known(coefficient),
+                    )?;
+                }
+
+                // assign input a to offset zero, column a of the region
+                a.assign("lhs", &mut region, config.a, 0)?;
+
+                // assign input b to offset zero, column b of the region
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // compute the output satisfying the gate
+                let c = coefficients
+                    .output(a.value(), b.value())
+                    .ok_or(Error::Synthesis)?;
+
+                // mutate the region and return
+                region
+                    // assign the output c as an advice into column a, offset one
+                    .assign_advice(|| "out", config.a, 1, || c)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+
+    /// Unary PLONK gate instruction implementation.
+    fn apply_unary(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        coefficients: Coefficients<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign a region of gates and return
+        layouter.assign_region(
+            // region name
+            || "plonk unary",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // assign the coefficients at offset zero, this enables the
+                // gate on cells zero and one
+                for (column, coefficient) in [
+                    (config.q_l, coefficients.q_l),
+                    (config.q_r, coefficients.q_r),
+                    (config.q_m, coefficients.q_m),
+                    (config.q_o, coefficients.q_o),
+                    (config.q_c, coefficients.q_c),
+                ] {
+                    region.assign_fixed(
+                        || "coefficient",
+                        column,
+                        0,
+                        || Value::known(coefficient),
+                    )?;
+                }
+
+                // assign input a to offset zero, column a of the region, no
+                // advice cell is needed in column b
+                a.assign("lhs", &mut region, config.a, 0)?;
+
+                // compute the output satisfying the gate
+                let c = coefficients
+                    .output(a.value(), Value::known(F::zero()))
+                    .ok_or(Error::Synthesis)?;
+
+                // mutate the region and return
+                region
+                    // assign the output c as an advice into column a, offset one
+                    .assign_advice(|| "out", config.a, 1, || c)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
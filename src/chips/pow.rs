@@ -0,0 +1,204 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Exponentiation instruction set.
+pub trait PowInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Exponentiation instruction.
+    /// Takes an input and a public exponent and returns the power.
+    fn pow(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: u64,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Exponentiation chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct PowConfig {
+    /// Advice column for the accumulator.
+    a: Column<Advice>,
+    /// Advice column for the base.
+    b: Column<Advice>,
+    /// Square selector, for zero bits of the exponent.
+    sel_square: Selector,
+    /// Square and multiply selector, for one bits of the exponent.
+    sel_square_mul: Selector,
+}
+
+/// Exponentiation chip definition.
+///
+/// Computes `a^n` for a public exponent `n` by square-and-multiply over the
+/// bits of `n`, most significant bit first. The first row holds the base, each
+/// following bit takes one row, so `a^n` costs `bit_length(n)` rows.
+pub struct PowChip<F: FieldExt> {
+    /// Exponentiation configuration.
+    config: PowConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Exponentiation chip implementation.
+impl<F: FieldExt> PowChip<F> {
+    /// Construct PowChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure PowChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_square = meta.selector();
+        let sel_square_mul = meta.selector();
+
+        // define the square-and-multiply gate
+        meta.create_gate(
+            // gate name
+            "pow",
+            // gate logic
+            |meta| {
+                // query the accumulator from a on the current rotation
+                let acc = meta.query_advice(a, Rotation::cur());
+                // query the base from b on the current rotation
+                let base = meta.query_advice(b, Rotation::cur());
+                // query the next accumulator from a on the next rotation
+                let next_acc = meta.query_advice(a, Rotation::next());
+                // query the base from b on the next rotation
+                let next_base = meta.query_advice(b, Rotation::next());
+                // query selectors
+                let sel_square = meta.query_selector(sel_square);
+                let sel_square_mul = meta.query_selector(sel_square_mul);
+
+                // if `sel_square != 0`, then `acc^2 = next_acc` is
+                // constrained.
+                // if `sel_square_mul != 0`, then `acc^2 * base = next_acc` is
+                // constrained.
+                // if either is enabled, the base is carried to the next row.
+                vec![
+                    sel_square.clone() * (acc.clone() * acc.clone() - next_acc.clone()),
+                    sel_square.clone() * (base.clone() - next_base.clone()),
+                    sel_square_mul.clone() * (acc.clone() * acc * base.clone() - next_acc),
+                    sel_square_mul * (base - next_base),
+                ]
+            },
+        );
+
+        // return config
+        PowConfig {
+            a,
+            b,
+            sel_square,
+            sel_square_mul,
+        }
+    }
+}
+
+/// Halo2 Chip implementation for PowChip.
+impl<F: FieldExt> Chip<F> for PowChip<F> {
+    /// Exponentiation configuration.
+    type Config = PowConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Exponentiation instruction set implementation for PowChip.
+impl<F: FieldExt> PowInstructions<F> for PowChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Exponentiation instruction implementation.
+    fn pow(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: u64,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "pow",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // `a^0 = 1`, copy the one from the constant column
+                if exponent == 0 {
+                    return region
+                        .assign_advice_from_constant(|| "one", config.a, 0, F::one())
+                        .map(Number);
+                }
+
+                // assign the base as the first accumulator to offset zero,
+                // column a of the region, and copy it to column b
+                let mut acc = a.assign("base", &mut region, config.a, 0)?;
+                let mut base = acc.copy_advice(|| "base", &mut region, config.b, 0)?;
+
+                // the most significant bit is consumed by the first row
+                let bits = 64 - exponent.leading_zeros() as usize;
+
+                for (row, bit) in (0..bits - 1).rev().enumerate() {
+                    let next = if exponent >> bit & 1 == 1 {
+                        // enable the square and multiply gate
+                        config.sel_square_mul.enable(&mut region, row)?;
+                        acc.value().copied() * acc.value() * base.value()
+                    } else {
+                        // enable the square gate
+                        config.sel_square.enable(&mut region, row)?;
+                        acc.value().copied() * acc.value()
+                    };
+
+                    // assign the next accumulator and carry the base
+                    acc = region.assign_advice(|| "acc", config.a, row + 1, || next)?;
+                    base = region.assign_advice(
+                        || "base",
+                        config.b,
+                        row + 1,
+                        || base.value().copied(),
+                    )?;
+                }
+
+                // return the last accumulator
+                Ok(Number(acc))
+            },
+        )
+    }
+}
@@ -0,0 +1,237 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Private exponentiation instruction set.
+pub trait PrivatePowInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Private exponentiation instruction.
+    /// Takes a base and a private exponent of at most `bits` bits and returns
+    /// the power. The layout only depends on `bits`, never on the exponent.
+    fn pow_private(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Private exponentiation chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct PrivatePowConfig {
+    /// Advice column for the accumulator and the base.
+    a: Column<Advice>,
+    /// Advice column for the exponent bits and their running sum.
+    b: Column<Advice>,
+    /// Conditional square and multiply selector.
+    sel_pow: Selector,
+}
+
+/// Private exponentiation chip definition.
+///
+/// Computes `a^e` for a private exponent `e` by decomposing `e` into `bits`
+/// boolean bits, most significant bit first. Each bit takes two rows,
+///
+/// | a    | b   |
+/// |------|-----|
+/// | acc  | bit |
+/// | base | sum |
+///
+/// where `sum` is the exponent recomposed from the bits seen so far. The next
+/// accumulator is `acc^2 * base` if the bit is set and `acc^2` otherwise. The
+/// final running sum is the exponent, so an exponent that does not fit into
+/// `bits` bits can not be witnessed.
+pub struct PrivatePowChip<F: FieldExt> {
+    /// Private exponentiation configuration.
+    config: PrivatePowConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Private exponentiation chip implementation.
+impl<F: FieldExt> PrivatePowChip<F> {
+    /// Construct PrivatePowChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure PrivatePowChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_pow = meta.selector();
+
+        // define the conditional square-and-multiply gate
+        meta.create_gate(
+            // gate name
+            "private pow",
+            // gate logic
+            |meta| {
+                // query the accumulator and the bit from the current rotation
+                let acc = meta.query_advice(a, Rotation::cur());
+                let bit = meta.query_advice(b, Rotation::cur());
+                // query the base and the running sum from the next rotation
+                let base = meta.query_advice(a, Rotation::next());
+                let sum = meta.query_advice(b, Rotation::next());
+                // query the next accumulator, base and running sum
+                let next_acc = meta.query_advice(a, Rotation(2));
+                let next_base = meta.query_advice(a, Rotation(3));
+                let next_sum = meta.query_advice(b, Rotation(3));
+                // query selector
+                let sel_pow = meta.query_selector(sel_pow);
+
+                let one = Expression::Constant(F::one());
+                let two = Expression::Constant(F::from(2));
+
+                // if `sel_pow != 0`, then the bit is boolean, the
+                // accumulator is squared and multiplied by the base if the bit
+                // is set, the base is carried and the bit is appended to the
+                // running sum.
+                vec![
+                    sel_pow.clone() * bit.clone() * (one.clone() - bit.clone()),
+                    sel_pow.clone()
+                        * (acc.clone() * acc * (one.clone() + bit.clone() * (base.clone() - one))
+                            - next_acc),
+                    sel_pow.clone() * (base - next_base),
+                    sel_pow * (sum * two + bit - next_sum),
+                ]
+            },
+        );
+
+        // return config
+        PrivatePowConfig { a, b, sel_pow }
+    }
+}
+
+/// Halo2 Chip implementation for PrivatePowChip.
+impl<F: FieldExt> Chip<F> for PrivatePowChip<F> {
+    /// Private exponentiation configuration.
+    type Config = PrivatePowConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Private exponentiation instruction set implementation for PrivatePowChip.
+impl<F: FieldExt> PrivatePowInstructions<F> for PrivatePowChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Private exponentiation instruction implementation.
+    fn pow_private(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        exponent: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // the initial running sum and the exponent would share a cell
+        if bits == 0 {
+            return Err(Error::Synthesis);
+        }
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "private pow",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // the accumulator starts at one and the running sum at zero,
+                // both copied from the constant column
+                let mut acc =
+                    region.assign_advice_from_constant(|| "acc", config.a, 0, F::one())?;
+                region.assign_advice_from_constant(|| "sum", config.b, 1, F::zero())?;
+
+                // assign the base to offset one, column a of the region
+                let mut base = a.assign("base", &mut region, config.a, 1)?;
+
+                // the running sum is only needed as a witness value, the last
+                // one is the exponent itself
+                let mut sum = Value::known(F::zero());
+
+                for (step, bit) in (0..bits).rev().enumerate() {
+                    let row = step * 2;
+
+                    // enable the gate, it constrains this step and the next
+                    config.sel_pow.enable(&mut region, row)?;
+
+                    // witness the bit of the exponent
+                    let bit = exponent.value().map(|e| {
+                        let bit = e.get_lower_128().checked_shr(bit as u32).unwrap_or(0) & 1;
+                        F::from(bit as u64)
+                    });
+                    region.assign_advice(|| "bit", config.b, row, || bit)?;
+
+                    // square the accumulator and multiply by the base if the
+                    // bit is set
+                    let next = acc
+                        .value()
+                        .zip(base.value())
+                        .zip(bit)
+                        .map(|((acc, base), bit)| match bit == F::one() {
+                            true => acc.square() * base,
+                            false => acc.square(),
+                        });
+                    acc = region.assign_advice(|| "acc", config.a, row + 2, || next)?;
+
+                    // carry the base
+                    base = region.assign_advice(
+                        || "base",
+                        config.a,
+                        row + 3,
+                        || base.value().copied(),
+                    )?;
+
+                    // append the bit to the running sum, the last running sum
+                    // is the exponent
+                    sum = sum.zip(bit).map(|(sum, bit)| sum.double() + bit);
+                    match step == bits - 1 {
+                        true => exponent.assign("exponent", &mut region, config.b, row + 3)?,
+                        false => region.assign_advice(|| "sum", config.b, row + 3, || sum)?,
+                    };
+                }
+
+                // return the last accumulator
+                Ok(Number(acc))
+            },
+        )
+    }
+}
@@ -0,0 +1,184 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Range check instruction set.
+pub trait RangeInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Range check instruction.
+    /// Takes an input, constrains it to `[0, 2^bits)` and returns it.
+    /// `bits` must be at most 128.
+    fn range_check(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Range check chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct RangeConfig {
+    /// Advice column for the running sum.
+    a: Column<Advice>,
+    /// Advice column for the bits.
+    b: Column<Advice>,
+    /// Bit decomposition selector.
+    sel_range: Selector,
+}
+
+/// Range check chip definition.
+///
+/// Decomposes a value `z_0` into bits, least significant bit first, with the
+/// running sum `z_i = 2·z_{i+1} + bit_i`, one row per bit. The last running
+/// sum `z_bits` is constrained to zero, so `z_0` fits into `bits` bits.
+pub struct RangeChip<F: FieldExt> {
+    /// Range check configuration.
+    config: RangeConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Range check chip implementation.
+impl<F: FieldExt> RangeChip<F> {
+    /// Construct RangeChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure RangeChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_range = meta.selector();
+
+        // define the bit decomposition gate
+        meta.create_gate(
+            // gate name
+            "range",
+            // gate logic
+            |meta| {
+                // query the running sum from a on the current rotation
+                let z = meta.query_advice(a, Rotation::cur());
+                // query the bit from b on the current rotation
+                let bit = meta.query_advice(b, Rotation::cur());
+                // query the next running sum from a on the next rotation
+                let next_z = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_range = meta.query_selector(sel_range);
+
+                // if `sel_range != 0`, then the bit is boolean and
+                // `z = 2 * next_z + bit` is constrained.
+                vec![
+                    sel_range.clone()
+                        * bit.clone()
+                        * (Expression::Constant(F::one()) - bit.clone()),
+                    sel_range * (z - next_z * Expression::Constant(F::from(2)) - bit),
+                ]
+            },
+        );
+
+        // return config
+        RangeConfig { a, b, sel_range }
+    }
+}
+
+/// Halo2 Chip implementation for RangeChip.
+impl<F: FieldExt> Chip<F> for RangeChip<F> {
+    /// Range check configuration.
+    type Config = RangeConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Range check instruction set implementation for RangeChip.
+impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Range check instruction implementation.
+    fn range_check(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "range",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // assign the input as the first running sum to offset zero,
+                // column a of the region
+                let value = a.assign("value", &mut region, config.a, 0)?;
+
+                // halve the running sum once per bit, widths are at most 128
+                // bits, so `get_lower_128` loses nothing for values in range
+                let mut z = value.value().map(|v| v.get_lower_128());
+                let mut last = value.clone();
+                for row in 0..bits {
+                    // enable the gate, it constrains this row and the next
+                    config.sel_range.enable(&mut region, row)?;
+
+                    // witness the least significant bit
+                    let bit = z.map(|z| F::from((z & 1) as u64));
+                    region.assign_advice(|| "bit", config.b, row, || bit)?;
+
+                    // witness the next running sum
+                    z = z.map(|z| z >> 1);
+                    last = region.assign_advice(
+                        || "z",
+                        config.a,
+                        row + 1,
+                        || z.map(|z| F::from_u128(z)),
+                    )?;
+                }
+
+                // the last running sum must be zero
+                region.constrain_constant(last.cell(), F::zero())?;
+
+                // return the range checked value
+                Ok(Number(value))
+            },
+        )
+    }
+}
@@ -0,0 +1,477 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::{
+    chips::{
+        arithmetic::{Input, Number},
+        range::{RangeChip, RangeConfig, RangeInstructions},
+    },
+    field,
+    rational::Reduction,
+};
+
+/// Number of bits of a reduced part, numerators and Bezout coefficients are
+/// offset by `2^63` into this range.
+pub const RATIONAL_BITS: usize = 64;
+
+/// Number of bits of a reduced denominator minus one, so it is positive.
+const DENOMINATOR_BITS: usize = 63;
+
+/// Fraction in the circuit, a numerator and a denominator.
+pub type Fraction<F> = (Input<F>, Input<F>);
+
+/// Rational instruction set.
+/// Every instruction expects reduced input fractions, with i64 numerators
+/// and positive i64 denominators, and returns a reduced output fraction.
+pub trait RationalInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Rational addition instruction.
+    /// Takes `a/b` and `c/d` and returns `(a·d + c·b) / (b·d)`, reduced.
+    fn rational_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+
+    /// Rational subtraction instruction.
+    /// Takes `a/b` and `c/d` and returns `(a·d - c·b) / (b·d)`, reduced.
+    fn rational_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+
+    /// Rational multiplication instruction.
+    /// Takes `a/b` and `c/d` and returns `(a·c) / (b·d)`, reduced.
+    fn rational_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+
+    /// Rational division instruction.
+    /// Takes `a/b` and `c/d` and returns `(a·d) / (b·c)`, reduced. A zero
+    /// divisor has no reduction.
+    fn rational_div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+
+    /// Rational reduction instruction.
+    /// Takes any fraction `p/q` with `q != 0` and returns its reduced form.
+    fn reduce(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+}
+
+/// Rational chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct RationalConfig {
+    /// Advice column for numerators.
+    a: Column<Advice>,
+    /// Advice column for denominators.
+    b: Column<Advice>,
+    /// Cross-multiplication selector of addition.
+    sel_add: Selector,
+    /// Cross-multiplication selector of subtraction.
+    sel_sub: Selector,
+    /// Cross-multiplication selector of multiplication.
+    sel_mul: Selector,
+    /// Cross-multiplication selector of division.
+    sel_div: Selector,
+    /// Reduction selector.
+    sel_reduce: Selector,
+    /// Range check chip configuration, for the reduced parts.
+    range_config: RangeConfig,
+}
+
+/// Rational chip definition.
+///
+/// Each operator cross multiplies its operands in one region,
+///
+/// | a | b |
+/// |---|---|
+/// | a | b |
+/// | c | d |
+/// | p | q |
+///
+/// with one selector per operator, e.g. `p = a·d + c·b` and `q = b·d` for
+/// addition. The result is then reduced,
+///
+/// | a        | b        |
+/// |----------|----------|
+/// | p        | q        |
+/// | g        | g^-1     |
+/// | p'       | q'       |
+/// | x        | y        |
+/// | p' + 2^63| q' - 1   |
+/// | x + 2^63 | y + 2^63 |
+///
+/// constraining `p = g·p'`, `q = g·q'`, `g·g^-1 = 1` and `x·p' + y·q' = 1`,
+/// with the last two rows range checked. So `p'`, `x` and `y` are i64 and
+/// `q'` is positive, nothing wraps around the field, the Bezout relation
+/// proves `p'` and `q'` coprime and `p'/q'` is the reduced form of `p/q`.
+pub struct RationalChip<F: FieldExt> {
+    /// Rational configuration.
+    config: RationalConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Rational chip implementation.
+impl<F: FieldExt> RationalChip<F> {
+    /// Construct RationalChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure RationalChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_add = meta.selector();
+        let sel_sub = meta.selector();
+        let sel_mul = meta.selector();
+        let sel_div = meta.selector();
+        let sel_reduce = meta.selector();
+
+        // define the cross-multiplication gate
+        meta.create_gate(
+            // gate name
+            "cross",
+            // gate logic
+            |meta| {
+                // query the operands from the current and next rotations
+                let lhs_n = meta.query_advice(a, Rotation::cur());
+                let lhs_d = meta.query_advice(b, Rotation::cur());
+                let rhs_n = meta.query_advice(a, Rotation::next());
+                let rhs_d = meta.query_advice(b, Rotation::next());
+                // query the output from the last rotation
+                let out_n = meta.query_advice(a, Rotation(2));
+                let out_d = meta.query_advice(b, Rotation(2));
+                // query selectors
+                let sel_add = meta.query_selector(sel_add);
+                let sel_sub = meta.query_selector(sel_sub);
+                let sel_mul = meta.query_selector(sel_mul);
+                let sel_div = meta.query_selector(sel_div);
+
+                // the denominator of a sum or difference is `b·d`
+                let common = lhs_d.clone() * rhs_d.clone();
+
+                vec![
+                    // if `sel_add != 0`, then `p = a·d + c·b` and `q = b·d`
+                    sel_add.clone()
+                        * (out_n.clone()
+                            - (lhs_n.clone() * rhs_d.clone() + rhs_n.clone() * lhs_d.clone())),
+                    sel_add * (out_d.clone() - common.clone()),
+                    // if `sel_sub != 0`, then `p = a·d - c·b` and `q = b·d`
+                    sel_sub.clone()
+                        * (out_n.clone()
+                            - (lhs_n.clone() * rhs_d.clone() - rhs_n.clone() * lhs_d.clone())),
+                    sel_sub * (out_d.clone() - common.clone()),
+                    // if `sel_mul != 0`, then `p = a·c` and `q = b·d`
+                    sel_mul.clone() * (out_n.clone() - lhs_n.clone() * rhs_n.clone()),
+                    sel_mul * (out_d.clone() - common),
+                    // if `sel_div != 0`, then `p = a·d` and `q = b·c`
+                    sel_div.clone() * (out_n - lhs_n * rhs_d),
+                    sel_div * (out_d - lhs_d * rhs_n),
+                ]
+            },
+        );
+
+        // define the reduction gate
+        meta.create_gate(
+            // gate name
+            "reduce",
+            // gate logic
+            |meta| {
+                // query the fraction, the gcd and its inverse
+                let p = meta.query_advice(a, Rotation::cur());
+                let q = meta.query_advice(b, Rotation::cur());
+                let g = meta.query_advice(a, Rotation::next());
+                let g_inv = meta.query_advice(b, Rotation::next());
+                // query the reduced fraction and the Bezout coefficients
+                let p_reduced = meta.query_advice(a, Rotation(2));
+                let q_reduced = meta.query_advice(b, Rotation(2));
+                let x = meta.query_advice(a, Rotation(3));
+                let y = meta.query_advice(b, Rotation(3));
+                // query the offset parts to range check
+                let p_offset = meta.query_advice(a, Rotation(4));
+                let q_offset = meta.query_advice(b, Rotation(4));
+                let x_offset = meta.query_advice(a, Rotation(5));
+                let y_offset = meta.query_advice(b, Rotation(5));
+                // query selector
+                let sel_reduce = meta.query_selector(sel_reduce);
+
+                let one = Expression::Constant(F::one());
+                let half = Expression::Constant(F::from(1 << (RATIONAL_BITS - 1)));
+
+                // if `sel_reduce != 0`, then `p = g·p'`, `q = g·q'`,
+                // `g·g^-1 = 1`, `x·p' + y·q' = 1` and the offset parts match
+                vec![
+                    sel_reduce.clone() * (p - g.clone() * p_reduced.clone()),
+                    sel_reduce.clone() * (q - g.clone() * q_reduced.clone()),
+                    sel_reduce.clone() * (g * g_inv - one.clone()),
+                    sel_reduce.clone()
+                        * (x.clone() * p_reduced.clone() + y.clone() * q_reduced.clone()
+                            - one.clone()),
+                    sel_reduce.clone() * (p_offset - (p_reduced + half.clone())),
+                    sel_reduce.clone() * (q_offset - (q_reduced - one)),
+                    sel_reduce.clone() * (x_offset - (x + half.clone())),
+                    sel_reduce * (y_offset - (y + half)),
+                ]
+            },
+        );
+
+        // return config
+        RationalConfig {
+            a,
+            b,
+            sel_add,
+            sel_sub,
+            sel_mul,
+            sel_div,
+            sel_reduce,
+            range_config,
+        }
+    }
+
+    /// Cross multiplies two fractions with the given operator selector, then
+    /// reduces the result.
+    fn cross(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        selector: Selector,
+        a: Fraction<F>,
+        b: Fraction<F>,
+        output: impl Fn(F, F, F, F) -> (F, F),
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        // get config
+        let config = self.config();
+
+        // compute the unreduced output
+        let out =
+            a.0.value()
+                .zip(a.1.value())
+                .zip(b.0.value().zip(b.1.value()))
+                .map(|((a, b), (c, d))| output(a, b, c, d));
+
+        let (p, q) = layouter.assign_region(
+            // region name
+            || "cross",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the operator's gate, set at region offset zero, it
+                // will constrain cells zero to two
+                selector.enable(&mut region, 0)?;
+
+                // assign the operands to offsets zero and one
+                a.0.assign("a", &mut region, config.a, 0)?;
+                a.1.assign("b", &mut region, config.b, 0)?;
+                b.0.assign("c", &mut region, config.a, 1)?;
+                b.1.assign("d", &mut region, config.b, 1)?;
+
+                // assign the output to offset two
+                let p = region.assign_advice(|| "p", config.a, 2, || out.map(|out| out.0))?;
+                let q = region.assign_advice(|| "q", config.b, 2, || out.map(|out| out.1))?;
+
+                Ok((Number(p), Number(q)))
+            },
+        )?;
+
+        self.reduce(layouter, (p.into(), q.into()))
+    }
+}
+
+/// Halo2 Chip implementation for RationalChip.
+impl<F: FieldExt> Chip<F> for RationalChip<F> {
+    /// Rational configuration.
+    type Config = RationalConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Rational instruction set implementation for RationalChip.
+impl<F: FieldExt> RationalInstructions<F> for RationalChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Rational addition instruction implementation.
+    fn rational_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        let selector = self.config().sel_add;
+        self.cross(layouter, selector, a, b, |a, b, c, d| {
+            (a * d + c * b, b * d)
+        })
+    }
+
+    /// Rational subtraction instruction implementation.
+    fn rational_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        let selector = self.config().sel_sub;
+        self.cross(layouter, selector, a, b, |a, b, c, d| {
+            (a * d - c * b, b * d)
+        })
+    }
+
+    /// Rational multiplication instruction implementation.
+    fn rational_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        let selector = self.config().sel_mul;
+        self.cross(layouter, selector, a, b, |a, b, c, d| (a * c, b * d))
+    }
+
+    /// Rational division instruction implementation.
+    fn rational_div(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+        b: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        let selector = self.config().sel_div;
+        self.cross(layouter, selector, a, b, |a, b, c, d| (a * d, b * c))
+    }
+
+    /// Rational reduction instruction implementation.
+    fn reduce(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Fraction<F>,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // witness the reduction of the signed parts, a zero denominator or
+        // parts past an i128 have none and fail the gate
+        let reduction = a.0.value().zip(a.1.value()).map(|(p, q)| {
+            field::to_signed(&p)
+                .zip(field::to_signed(&q))
+                .and_then(|(p, q)| Reduction::new(p, q))
+        });
+        let part = |part: fn(&Reduction) -> i128| -> Value<F> {
+            reduction.map(|r| {
+                r.as_ref()
+                    .map_or(F::zero(), |r| field::from_signed(part(r)))
+            })
+        };
+        let half = F::from(1 << (RATIONAL_BITS - 1));
+        let g = part(|r| r.gcd);
+        let g_inv = g.map(|g| g.invert().unwrap_or(F::zero()));
+        let (p_reduced, q_reduced) = (part(|r| r.numerator), part(|r| r.denominator));
+        let (x, y) = (part(|r| r.x), part(|r| r.y));
+
+        let (p, q, offsets) = layouter.assign_region(
+            // region name
+            || "reduce",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the reduction gate, set at region offset zero, it
+                // will constrain cells zero to five
+                config.sel_reduce.enable(&mut region, 0)?;
+
+                // assign the fraction to offset zero
+                a.0.assign("p", &mut region, config.a, 0)?;
+                a.1.assign("q", &mut region, config.b, 0)?;
+
+                // assign the gcd and its inverse to offset one
+                region.assign_advice(|| "g", config.a, 1, || g)?;
+                region.assign_advice(|| "g^-1", config.b, 1, || g_inv)?;
+
+                // assign the reduced fraction to offset two
+                let p = region.assign_advice(|| "p'", config.a, 2, || p_reduced)?;
+                let q = region.assign_advice(|| "q'", config.b, 2, || q_reduced)?;
+
+                // assign the Bezout coefficients to offset three
+                region.assign_advice(|| "x", config.a, 3, || x)?;
+                region.assign_advice(|| "y", config.b, 3, || y)?;
+
+                // assign the offset parts to offsets four and five
+                let offsets = [
+                    region.assign_advice(
+                        || "p' + 2^63",
+                        config.a,
+                        4,
+                        || p_reduced + Value::known(half),
+                    )?,
+                    region.assign_advice(
+                        || "q' - 1",
+                        config.b,
+                        4,
+                        || q_reduced - Value::known(F::one()),
+                    )?,
+                    region.assign_advice(|| "x + 2^63", config.a, 5, || x + Value::known(half))?,
+                    region.assign_advice(|| "y + 2^63", config.b, 5, || y + Value::known(half))?,
+                ];
+
+                Ok((Number(p), Number(q), offsets))
+            },
+        )?;
+
+        // the numerator and the Bezout coefficients are i64, the denominator
+        // is positive
+        let [p_offset, q_offset, x_offset, y_offset] = offsets;
+        range_chip.range_check(layouter, Number(p_offset).into(), RATIONAL_BITS)?;
+        range_chip.range_check(layouter, Number(q_offset).into(), DENOMINATOR_BITS)?;
+        range_chip.range_check(layouter, Number(x_offset).into(), RATIONAL_BITS)?;
+        range_chip.range_check(layouter, Number(y_offset).into(), RATIONAL_BITS)?;
+
+        Ok((p, q))
+    }
+}
@@ -0,0 +1,194 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter},
+    plonk::Error,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    compare::{CompareChip, CompareConfig, CompareInstructions},
+    select::{SelectChip, SelectConfig, SelectInstructions},
+    wrapping::{WrappingChip, WrappingConfig},
+};
+
+/// Saturating arithmetic instruction set.
+/// Each instruction takes two u64 inputs and clamps the result to
+/// `[0, u64::MAX]`, matching Rust's `u64::saturating_*`.
+pub trait SaturatingInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Saturating addition instruction.
+    /// Takes two u64 inputs and returns `min(a + b, u64::MAX)`.
+    fn saturating_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Saturating subtraction instruction.
+    /// Takes two u64 inputs and returns `max(a - b, 0)`.
+    fn saturating_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Saturating multiplication instruction.
+    /// Takes two u64 inputs and returns `min(a·b, u64::MAX)`.
+    fn saturating_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Saturating arithmetic chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct SaturatingConfig {
+    /// Wrapping arithmetic chip configuration, for the result and the carry.
+    wrapping_config: WrappingConfig,
+    /// Comparison chip configuration, for the overflow flag of products.
+    compare_config: CompareConfig,
+    /// Conditional select chip configuration, for clamping to the bound.
+    select_config: SelectConfig,
+}
+
+/// Saturating arithmetic chip definition.
+///
+/// The chip has no gate of its own. Each instruction computes the wrapping
+/// result and its carry with the wrapping chip, derives a boolean overflow
+/// flag, the carry or borrow itself, or `high != 0` for a product, and
+/// selects between the bound and the wrapping result with the select gate.
+pub struct SaturatingChip<F: FieldExt> {
+    /// Saturating arithmetic configuration.
+    config: SaturatingConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Saturating arithmetic chip implementation.
+impl<F: FieldExt> SaturatingChip<F> {
+    /// Construct SaturatingChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure SaturatingChip and return the Config.
+    pub fn configure(
+        wrapping_config: WrappingConfig,
+        compare_config: CompareConfig,
+        select_config: SelectConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // the saturating chip only composes the gates of other chips
+        SaturatingConfig {
+            wrapping_config,
+            compare_config,
+            select_config,
+        }
+    }
+
+    /// Returns the wrapping chip.
+    fn wrapping_chip(&self) -> WrappingChip<F> {
+        WrappingChip::<F>::construct(self.config().wrapping_config.clone(), ())
+    }
+
+    /// Selects the bound if the boolean overflow flag is set and the wrapping
+    /// result otherwise.
+    fn clamp(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        overflow: Number<F>,
+        bound: u64,
+        out: Number<F>,
+    ) -> Result<Number<F>, Error> {
+        // construct the select chip
+        let select_chip = SelectChip::<F>::construct(self.config().select_config.clone(), ());
+
+        select_chip.select(
+            layouter,
+            overflow.into(),
+            Input::Constant(F::from(bound)),
+            out.into(),
+        )
+    }
+}
+
+/// Halo2 Chip implementation for SaturatingChip.
+impl<F: FieldExt> Chip<F> for SaturatingChip<F> {
+    /// Saturating arithmetic configuration.
+    type Config = SaturatingConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Saturating arithmetic instruction set implementation for SaturatingChip.
+impl<F: FieldExt> SaturatingInstructions<F> for SaturatingChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Saturating addition instruction implementation.
+    fn saturating_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // the carry is the overflow flag
+        let (out, carry) = self.wrapping_chip().overflowing_add(layouter, a, b)?;
+        self.clamp(layouter, carry, u64::MAX, out)
+    }
+
+    /// Saturating subtraction instruction implementation.
+    fn saturating_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // the borrow is the underflow flag
+        let (out, borrow) = self.wrapping_chip().overflowing_sub(layouter, a, b)?;
+        self.clamp(layouter, borrow, 0, out)
+    }
+
+    /// Saturating multiplication instruction implementation.
+    fn saturating_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // the product overflows if its high limb is not zero
+        let (out, high) = self.wrapping_chip().overflowing_mul(layouter, a, b)?;
+
+        // construct the comparison chip
+        let compare_chip = CompareChip::<F>::construct(self.config().compare_config.clone(), ());
+        let overflow = compare_chip.ne(layouter, high.into(), Input::Constant(F::zero()))?;
+
+        self.clamp(layouter, overflow, u64::MAX, out)
+    }
+}
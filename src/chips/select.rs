@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::arithmetic::{Input, Number};
+
+/// Conditional select instruction set.
+pub trait SelectInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Conditional select instruction.
+    /// Takes a boolean condition and two inputs and returns `a` if the
+    /// condition is `1` and `b` if it is `0`.
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        condition: Input<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Conditional select chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct SelectConfig {
+    /// Advice column for the condition and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_a` and `input_b`.
+    b: Column<Advice>,
+    /// Select selector.
+    sel_select: Selector,
+}
+
+/// Conditional select chip definition.
+pub struct SelectChip<F: FieldExt> {
+    /// Conditional select configuration.
+    config: SelectConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Conditional select chip implementation.
+impl<F: FieldExt> SelectChip<F> {
+    /// Construct SelectChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure SelectChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_select = meta.selector();
+
+        // define the select gate
+        meta.create_gate(
+            // gate name
+            "select",
+            // gate logic
+            |meta| {
+                // query the condition from a on the current rotation
+                let condition = meta.query_advice(a, Rotation::cur());
+                // query the inputs from b on the current and next rotation
+                let lhs = meta.query_advice(b, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::next());
+                // query the output from a on the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                // query selector
+                let sel_select = meta.query_selector(sel_select);
+
+                let one = Expression::Constant(F::one());
+
+                // if `sel_select != 0`, then the condition is boolean and
+                // `condition·lhs + (1 - condition)·rhs = out` is constrained.
+                vec![
+                    sel_select.clone() * condition.clone() * (one.clone() - condition.clone()),
+                    sel_select * (condition.clone() * lhs + (one - condition) * rhs - out),
+                ]
+            },
+        );
+
+        // return config
+        SelectConfig { a, b, sel_select }
+    }
+}
+
+/// Halo2 Chip implementation for SelectChip.
+impl<F: FieldExt> Chip<F> for SelectChip<F> {
+    /// Conditional select configuration.
+    type Config = SelectConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Conditional select instruction set implementation for SelectChip.
+impl<F: FieldExt> SelectInstructions<F> for SelectChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Conditional select instruction implementation.
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        condition: Input<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // assign region and return
+        layouter.assign_region(
+            // region name
+            || "select",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the select gate, set at region offset zero, it will
+                // constrain cells zero and one
+                config.sel_select.enable(&mut region, 0)?;
+
+                // assign the condition to offset zero, column a of the region
+                condition.assign("condition", &mut region, config.a, 0)?;
+
+                // assign the inputs to offsets zero and one, column b
+                a.assign("lhs", &mut region, config.b, 0)?;
+                b.assign("rhs", &mut region, config.b, 1)?;
+
+                // select the output, a non-boolean condition fails the gate
+                let out = condition
+                    .value()
+                    .zip(a.value())
+                    .zip(b.value())
+                    .map(|((condition, a), b)| condition * a + (F::one() - condition) * b);
+
+                // mutate the region and return
+                region
+                    // assign the output as an advice value to column a,
+                    // offset one
+                    .assign_advice(|| "out", config.a, 1, || out)
+                    // map the result to `Number`
+                    .map(Number)
+            },
+        )
+    }
+}
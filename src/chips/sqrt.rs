@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    compare::BITS,
+    range::{RangeChip, RangeConfig, RangeInstructions},
+};
+
+/// Bit width of the square root of a u64.
+const ROOT_BITS: usize = BITS / 2;
+
+/// Bit width of the remainder and its complement, both at most `2·s`.
+const REMAINDER_BITS: usize = ROOT_BITS + 1;
+
+/// Square root instruction set.
+pub trait SqrtInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Square root instruction.
+    /// Takes a u64 input and returns `floor(sqrt(a))`.
+    fn sqrt(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error>;
+}
+
+/// Square root chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct SqrtConfig {
+    /// Advice column for `input_a` and the remainder.
+    a: Column<Advice>,
+    /// Advice column for the root and the remainder's complement.
+    b: Column<Advice>,
+    /// Square root selector.
+    sel_sqrt: Selector,
+    /// Range check chip configuration, for the input, the root and the
+    /// remainders.
+    range_config: RangeConfig,
+}
+
+/// Square root chip definition.
+///
+/// Witnesses the root `s` and the remainder `r = a - s^2`,
+///
+/// | a  | b  |
+/// |----|----|
+/// | a  | s  |
+/// | r  | r' |
+///
+/// constraining `a = s^2 + r` and `r' = 2·s - r`. With `s` range checked to
+/// 32 bits and both remainders to 33 bits, nothing wraps around the field,
+/// so `s^2 <= a` and `a - s^2 <= 2·s`, which is `a < (s + 1)^2`.
+pub struct SqrtChip<F: FieldExt> {
+    /// Square root configuration.
+    config: SqrtConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Square root chip implementation.
+impl<F: FieldExt> SqrtChip<F> {
+    /// Construct SqrtChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure SqrtChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selector
+        let sel_sqrt = meta.selector();
+
+        // define the square root gate
+        meta.create_gate(
+            // gate name
+            "sqrt",
+            // gate logic
+            |meta| {
+                // query the input and the root from the current rotation
+                let value = meta.query_advice(a, Rotation::cur());
+                let root = meta.query_advice(b, Rotation::cur());
+                // query the remainder and its complement from the next rotation
+                let r = meta.query_advice(a, Rotation::next());
+                let complement = meta.query_advice(b, Rotation::next());
+                // query selector
+                let sel_sqrt = meta.query_selector(sel_sqrt);
+
+                let two = Expression::Constant(F::from(2));
+
+                // if `sel_sqrt != 0`, then `a = s^2 + r` and `r' = 2·s - r`
+                vec![
+                    sel_sqrt.clone() * (value - (root.clone() * root.clone() + r.clone())),
+                    sel_sqrt * (complement - (two * root - r)),
+                ]
+            },
+        );
+
+        // return config
+        SqrtConfig {
+            a,
+            b,
+            sel_sqrt,
+            range_config,
+        }
+    }
+}
+
+/// Halo2 Chip implementation for SqrtChip.
+impl<F: FieldExt> Chip<F> for SqrtChip<F> {
+    /// Square root configuration.
+    type Config = SqrtConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Square root instruction set implementation for SqrtChip.
+impl<F: FieldExt> SqrtInstructions<F> for SqrtChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Square root instruction implementation.
+    fn sqrt(&self, layouter: &mut impl Layouter<F>, a: Input<F>) -> Result<Self::Num, Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // constants are u64 by construction, everything else is range checked
+        let a = match a {
+            Input::Constant(_) => a,
+            _ => range_chip.range_check(layouter, a, BITS)?.into(),
+        };
+
+        // witness the root and the remainders, an input past u64 is
+        // truncated and fails its range check
+        let value = a.value().map(|a| a.get_lower_128() as u64);
+        let root = value.map(|a| a.isqrt());
+        let r = value.zip(root).map(|(a, s)| F::from(a - s * s));
+        let complement = value.zip(root).map(|(a, s)| F::from(2 * s - (a - s * s)));
+
+        let (root, r, complement) = layouter.assign_region(
+            // region name
+            || "sqrt",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the square root gate, set at region offset zero, it
+                // will constrain cells zero and one
+                config.sel_sqrt.enable(&mut region, 0)?;
+
+                // assign the input and the root to offset zero
+                a.assign("a", &mut region, config.a, 0)?;
+                let root =
+                    region.assign_advice(|| "s", config.b, 0, || root.map(|s| F::from(s)))?;
+
+                // assign the remainder and its complement to offset one
+                let r = region.assign_advice(|| "r", config.a, 1, || r)?;
+                let complement = region.assign_advice(|| "r'", config.b, 1, || complement)?;
+
+                Ok((Number(root), Number(r), Number(complement)))
+            },
+        )?;
+
+        // the remainder and its complement are not negative
+        range_chip.range_check(layouter, r.into(), REMAINDER_BITS)?;
+        range_chip.range_check(layouter, complement.into(), REMAINDER_BITS)?;
+
+        // the root fits into half the bits, so its square does not wrap
+        range_chip.range_check(layouter, root.into(), ROOT_BITS)
+    }
+}
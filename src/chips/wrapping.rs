@@ -0,0 +1,334 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+// we import `Number` and `Input` from the top level chip to avoid redeclaring
+// the same types for each operator chip.
+use crate::chips::{
+    arithmetic::{Input, Number},
+    compare::BITS,
+    range::{RangeChip, RangeConfig, RangeInstructions},
+};
+
+/// Wrapping arithmetic instruction set.
+/// Each instruction takes two u64 inputs and returns the low 64 bits of the
+/// result, matching Rust's `u64::wrapping_*`.
+pub trait WrappingInstructions<F: FieldExt>: Chip<F> {
+    /// Numeric variable.
+    type Num;
+
+    /// Wrapping addition instruction.
+    /// Takes two u64 inputs and returns `a + b mod 2^64`.
+    fn wrapping_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Wrapping subtraction instruction.
+    /// Takes two u64 inputs and returns `a - b mod 2^64`.
+    fn wrapping_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Wrapping multiplication instruction.
+    /// Takes two u64 inputs and returns `a·b mod 2^64`.
+    fn wrapping_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Wrapping arithmetic chip configuration.
+/// Derived during `Chip::configure`.
+#[derive(Clone, Debug)]
+pub struct WrappingConfig {
+    /// Advice column for `input_a` and `output`.
+    a: Column<Advice>,
+    /// Advice column for `input_b` and the carry, borrow or high limb.
+    b: Column<Advice>,
+    /// Wrapping addition selector.
+    sel_add: Selector,
+    /// Wrapping subtraction selector.
+    sel_sub: Selector,
+    /// Wrapping multiplication selector.
+    sel_mul: Selector,
+    /// Range check chip configuration, for the operands, output and high limb.
+    range_config: RangeConfig,
+}
+
+/// Wrapping arithmetic chip definition.
+///
+/// Each instruction witnesses the output `c` next to the part of the result
+/// that does not fit into 64 bits,
+///
+/// | a | b            |
+/// |---|--------------|
+/// | a | b            |
+/// | c | carry / high |
+///
+/// constraining `a + b = carry·2^64 + c`, `a - b = c - borrow·2^64` or
+/// `a·b = high·2^64 + c`. Carries and borrows are boolean, the high limb of a
+/// product and the output are range checked to u64. With the operands range
+/// checked as well, no side of an equation reaches the field modulus, so `c`
+/// is the low 64 bits of the integer result.
+pub struct WrappingChip<F: FieldExt> {
+    /// Wrapping arithmetic configuration.
+    config: WrappingConfig,
+    /// Placeholder data.
+    _marker: PhantomData<F>,
+}
+
+/// Wrapping arithmetic chip implementation.
+impl<F: FieldExt> WrappingChip<F> {
+    /// Construct WrappingChip and return.
+    pub fn construct(
+        config: <Self as Chip<F>>::Config,
+        _loaded: <Self as Chip<F>>::Loaded,
+    ) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure WrappingChip and return the Config.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        range_config: RangeConfig,
+    ) -> <Self as Chip<F>>::Config {
+        // enable equality on columns
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+
+        // get selectors
+        let sel_add = meta.selector();
+        let sel_sub = meta.selector();
+        let sel_mul = meta.selector();
+
+        // define the wrapping gate
+        meta.create_gate(
+            // gate name
+            "wrapping",
+            // gate logic
+            |meta| {
+                // query the operands from the current rotation
+                let lhs = meta.query_advice(a, Rotation::cur());
+                let rhs = meta.query_advice(b, Rotation::cur());
+                // query the output and the carry from the next rotation
+                let out = meta.query_advice(a, Rotation::next());
+                let carry = meta.query_advice(b, Rotation::next());
+                // query selectors
+                let sel_add = meta.query_selector(sel_add);
+                let sel_sub = meta.query_selector(sel_sub);
+                let sel_mul = meta.query_selector(sel_mul);
+
+                let one = Expression::Constant(F::one());
+                let range = Expression::Constant(F::from_u128(1 << BITS));
+
+                // if `sel_add != 0`, then the carry is boolean and
+                // `a + b - carry·2^64 = out`.
+                // if `sel_sub != 0`, then the borrow is boolean and
+                // `a - b + borrow·2^64 = out`.
+                // if `sel_mul != 0`, then `a·b - high·2^64 = out`.
+                vec![
+                    sel_add.clone() * carry.clone() * (one.clone() - carry.clone()),
+                    sel_add
+                        * (lhs.clone() + rhs.clone() - carry.clone() * range.clone() - out.clone()),
+                    sel_sub.clone() * carry.clone() * (one - carry.clone()),
+                    sel_sub
+                        * (lhs.clone() - rhs.clone() + carry.clone() * range.clone() - out.clone()),
+                    sel_mul * (lhs * rhs - carry * range - out),
+                ]
+            },
+        );
+
+        // return config
+        WrappingConfig {
+            a,
+            b,
+            sel_add,
+            sel_sub,
+            sel_mul,
+            range_config,
+        }
+    }
+
+    /// Range checks both operands and assigns a wrapping region under the
+    /// given selector, returning the output and the carry. `op` returns the
+    /// low 64 bits and the carry of the integer result, which is range checked
+    /// to `carry_bits` bits unless the gate constrains it to a boolean.
+    fn apply(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+        selector: Selector,
+        carry_bits: Option<usize>,
+        op: fn(u64, u64) -> (u64, u64),
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        // get config
+        let config = self.config();
+
+        // construct the range chip
+        let range_chip = RangeChip::<F>::construct(config.range_config.clone(), ());
+
+        // constants are u64 by construction, everything else is range checked
+        let a = match a {
+            Input::Constant(_) => a,
+            _ => range_chip.range_check(layouter, a, BITS)?.into(),
+        };
+        let b = match b {
+            Input::Constant(_) => b,
+            _ => range_chip.range_check(layouter, b, BITS)?.into(),
+        };
+
+        // witness the output and the carry
+        let result = a
+            .value()
+            .zip(b.value())
+            .map(|(a, b)| op(a.get_lower_128() as u64, b.get_lower_128() as u64));
+        let out = result.map(|(out, _)| F::from(out));
+        let carry = result.map(|(_, carry)| F::from(carry));
+
+        let (out, carry) = layouter.assign_region(
+            // region name
+            || "wrapping",
+            // assignment
+            |mut region: Region<'_, F>| {
+                // enable the wrapping gate, set at region offset zero, it will
+                // constrain cells zero and one
+                selector.enable(&mut region, 0)?;
+
+                // assign the operands to offset zero
+                a.assign("lhs", &mut region, config.a, 0)?;
+                b.assign("rhs", &mut region, config.b, 0)?;
+
+                // assign the output and the carry to offset one
+                let out = region.assign_advice(|| "out", config.a, 1, || out)?;
+                let carry = region.assign_advice(|| "carry", config.b, 1, || carry)?;
+
+                Ok((Number(out), Number(carry)))
+            },
+        )?;
+
+        // the carry must fit, unless the gate already constrains it
+        let carry = match carry_bits {
+            Some(bits) => range_chip.range_check(layouter, carry.into(), bits)?,
+            None => carry,
+        };
+
+        // the output must fit into u64
+        let out = range_chip.range_check(layouter, out.into(), BITS)?;
+
+        Ok((out, carry))
+    }
+
+    /// Overflowing addition, returns `a + b mod 2^64` and the boolean carry.
+    pub fn overflowing_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        self.apply(layouter, a, b, self.config().sel_add, None, |a, b| {
+            let (out, carry) = a.overflowing_add(b);
+            (out, carry as u64)
+        })
+    }
+
+    /// Overflowing subtraction, returns `a - b mod 2^64` and the boolean
+    /// borrow.
+    pub fn overflowing_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        self.apply(layouter, a, b, self.config().sel_sub, None, |a, b| {
+            let (out, borrow) = a.overflowing_sub(b);
+            (out, borrow as u64)
+        })
+    }
+
+    /// Overflowing multiplication, returns `a·b mod 2^64` and the high limb
+    /// of the product.
+    pub fn overflowing_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<(Number<F>, Number<F>), Error> {
+        self.apply(layouter, a, b, self.config().sel_mul, Some(BITS), |a, b| {
+            let product = a as u128 * b as u128;
+            (product as u64, (product >> BITS) as u64)
+        })
+    }
+}
+
+/// Halo2 Chip implementation for WrappingChip.
+impl<F: FieldExt> Chip<F> for WrappingChip<F> {
+    /// Wrapping arithmetic configuration.
+    type Config = WrappingConfig;
+    /// Loaded data.
+    type Loaded = ();
+
+    /// Returns a configuration reference.
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    /// Returns the loaded data reference.
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// Wrapping arithmetic instruction set implementation for WrappingChip.
+impl<F: FieldExt> WrappingInstructions<F> for WrappingChip<F> {
+    /// Numeric type definition.
+    type Num = Number<F>;
+
+    /// Wrapping addition instruction implementation.
+    fn wrapping_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.overflowing_add(layouter, a, b).map(|(out, _)| out)
+    }
+
+    /// Wrapping subtraction instruction implementation.
+    fn wrapping_sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.overflowing_sub(layouter, a, b).map(|(out, _)| out)
+    }
+
+    /// Wrapping multiplication instruction implementation.
+    fn wrapping_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: Input<F>,
+        b: Input<F>,
+    ) -> Result<Self::Num, Error> {
+        self.overflowing_mul(layouter, a, b).map(|(out, _)| out)
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, io};
 
 use halo2_proofs::{dev::VerifyFailure, plonk::Error};
 
@@ -12,6 +12,35 @@ pub enum ParserError {
     TooManyInputs,
     /// Thrown when not enough whitespace-separated inputs are provided.
     NotEnoughInputs,
+    /// Thrown when parentheses are not balanced.
+    UnbalancedParentheses,
+    /// Thrown when a `?` is not followed by a variable name.
+    InvalidVariable,
+    /// Thrown when a public exponent does not fold into a u64.
+    InvalidExponent,
+    /// Thrown when a statement is neither of the form `?x >= threshold` nor
+    /// `?p * ?q == n`.
+    InvalidStatement,
+    /// Thrown when a conditional `?` is not followed by a `:`.
+    InvalidConditional,
+    /// Thrown when a shift is not a constant below 64.
+    InvalidShift,
+    /// Thrown when a modulus is not a constant of at least one, or the
+    /// reduced expression has a private exponent.
+    InvalidModulus,
+    /// Thrown when a field name is neither `fp` nor `fq`.
+    InvalidField,
+    /// Thrown when a fixed-point scale has too many decimal places.
+    InvalidScale,
+    /// Thrown when a rounding mode is not `floor`, `ceil` or `half-up`.
+    InvalidRounding,
+    /// Thrown when a function is unknown, called with the wrong number of
+    /// arguments or not supported by the mode.
+    InvalidFunction,
+    /// Thrown when a public least common multiple does not fit into a u64.
+    InvalidLcm,
+    /// Thrown when a unary minus is used in an unsigned mode.
+    InvalidNegation,
 }
 
 /// Debug implementation for Parser Error.
@@ -20,7 +49,7 @@ impl fmt::Debug for ParserError {
         match self {
             ParserError::InvalidOperator => write!(
                 f,
-                "invalid operator. valid operators include `+`, `-`, and `*`."
+                "invalid operator. valid operators include `+`, `-`, `*`, `/` (field and rational modes only), `^`, `<`, `<=`, `>`, `>=`, `==`, `!=`, `&&`, `||`, `!`, `&`, `|`, `xor`, `<<`, `>>`, `+%`, `-%`, `*%`, `sat+`, `sat-`, `sat*` and `mod`."
             ),
             ParserError::InvalidOperand => write!(f, "invalid operand, operand must be numeric"),
             ParserError::TooManyInputs => {
@@ -29,6 +58,48 @@ impl fmt::Debug for ParserError {
             ParserError::NotEnoughInputs => {
                 write!(f, "not enough inputs, valid format is `a operator b`")
             }
+            ParserError::UnbalancedParentheses => write!(f, "unbalanced parentheses"),
+            ParserError::InvalidVariable => {
+                write!(f, "invalid variable, valid format is `?name`")
+            }
+            ParserError::InvalidExponent => {
+                write!(f, "invalid exponent, a public exponent must fit into a u64")
+            }
+            ParserError::InvalidStatement => {
+                write!(
+                    f,
+                    "invalid statement, valid formats are `prove ?x >= threshold` and `prove ?p * ?q == n`"
+                )
+            }
+            ParserError::InvalidConditional => {
+                write!(f, "invalid conditional, valid format is `condition ? a : b`")
+            }
+            ParserError::InvalidModulus => write!(
+                f,
+                "invalid modulus, valid format is `a mod m` for a constant `m` of at least 1, exponents under a modulus must be public"
+            ),
+            ParserError::InvalidShift => {
+                write!(f, "invalid shift, a shift must be a constant below 64")
+            }
+            ParserError::InvalidField => write!(f, "invalid field, valid fields are `fp` and `fq`"),
+            ParserError::InvalidScale => {
+                write!(f, "invalid scale, at most 18 decimal places are supported")
+            }
+            ParserError::InvalidRounding => write!(
+                f,
+                "invalid rounding mode, valid modes are `floor`, `ceil` and `half-up`"
+            ),
+            ParserError::InvalidFunction => write!(
+                f,
+                "invalid function call, valid formats are `sqrt(a)`, `abs(a)`, `gcd(a, b)`, `lcm(a, b)`, `min(a, b, ...)` and `max(a, b, ...)` in integer mode"
+            ),
+            ParserError::InvalidLcm => {
+                write!(f, "invalid lcm, a least common multiple must fit into a u64")
+            }
+            ParserError::InvalidNegation => write!(
+                f,
+                "invalid negation, unary `-` is only supported in field and rational modes"
+            ),
         }
     }
 }
@@ -42,6 +113,17 @@ pub enum CircuitError {
     /// Thrown when no operation has been specified.
     /// This should never happen.
     NoOperation,
+    /// Thrown when the circuit does not support an operator.
+    UnsupportedOperator,
+    /// Thrown when a private value does not fit into its limbs.
+    ValueTooLarge,
+    /// Thrown when a real proof fails verification.
+    InvalidProof(Error),
+    /// Thrown when a rational calculation divides by zero or a reduced part
+    /// does not fit into an i64.
+    UndefinedRational,
+    /// Thrown when a private least common multiple does not fit into a u64.
+    LcmOverflow,
 }
 
 impl fmt::Debug for CircuitError {
@@ -56,6 +138,82 @@ impl fmt::Debug for CircuitError {
             CircuitError::NoOperation => {
                 write!(f, "no operation is set (this should never happen.")
             }
+            CircuitError::UnsupportedOperator => {
+                write!(f, "operator is not supported by this circuit")
+            }
+            CircuitError::ValueTooLarge => {
+                write!(f, "private value does not fit into the variable limbs")
+            }
+            CircuitError::InvalidProof(proof_error) => {
+                write!(f, "proof verification failed: {}", proof_error)
+            }
+            CircuitError::UndefinedRational => write!(
+                f,
+                "rational result is undefined, it divides by zero or does not fit into an i64"
+            ),
+            CircuitError::LcmOverflow => {
+                write!(f, "least common multiple does not fit into a u64")
+            }
+        }
+    }
+}
+
+/// Ledger Errors.
+pub enum LedgerError {
+    /// Thrown when reading or writing the ledger file fails.
+    Io(io::Error),
+    /// Thrown when the ledger file cannot be parsed.
+    MalformedLedger,
+    /// Thrown when the ledger's opening does not match its latest commitment.
+    CommitmentMismatch,
+    /// Thrown when proving or verifying an entry of the ledger fails.
+    Circuit(CircuitError),
+    /// Thrown when a field other than `fp` is requested for the ledger.
+    UnsupportedField,
+}
+
+impl fmt::Debug for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::Io(io_error) => write!(f, "ledger io error: {}", io_error),
+            LedgerError::MalformedLedger => write!(f, "malformed ledger file"),
+            LedgerError::CommitmentMismatch => {
+                write!(f, "ledger opening does not match the latest commitment")
+            }
+            LedgerError::Circuit(circuit_error) => write!(f, "{:?}", circuit_error),
+            LedgerError::UnsupportedField => {
+                write!(
+                    f,
+                    "the ledger is only supported over `fp`, not over `--field fq`"
+                )
+            }
+        }
+    }
+}
+
+/// Proof Artifact Errors.
+pub enum ProofError {
+    /// Thrown when reading or writing the proof artifact fails.
+    Io(io::Error),
+    /// Thrown when the proof artifact cannot be parsed.
+    MalformedArtifact,
+    /// Thrown when proving or verifying fails.
+    Circuit(CircuitError),
+    /// Thrown when a proof artifact is requested for a command that does not
+    /// write one.
+    UnsupportedCommand,
+}
+
+impl fmt::Debug for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::Io(io_error) => write!(f, "proof artifact io error: {}", io_error),
+            ProofError::MalformedArtifact => write!(f, "malformed proof artifact"),
+            ProofError::Circuit(circuit_error) => write!(f, "{:?}", circuit_error),
+            ProofError::UnsupportedCommand => write!(
+                f,
+                "`--proof` is only supported for calculations and statements, not for the ledger or verification"
+            ),
         }
     }
 }
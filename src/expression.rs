@@ -0,0 +1,1598 @@
+use std::{iter::Peekable, vec::IntoIter};
+
+use halo2_proofs::arithmetic::FieldExt;
+
+use crate::{
+    bigint::BigUint,
+    chips::bitwise::BITS,
+    errors::ParserError,
+    field,
+    fixed::FixedPoint,
+    rational,
+    zk_calculator::{FromToken, Function, Operand, Operator},
+};
+
+/// Length of the longest operator token.
+const MAX_OPERATOR_LEN: usize = 4;
+
+/// Expression syntax tree.
+#[derive(Clone, Debug)]
+pub enum Expression {
+    /// Numeric literal, baked into the circuit as a constant.
+    Constant(Operand),
+    /// Field element literal past u64, below the field modulus, baked into
+    /// the circuit as a single constant.
+    Element(BigUint),
+    /// Private variable, indexes into the parsed variable list.
+    Variable(usize),
+    /// Binary operation on a left and right hand side.
+    Binary(Operator, Box<Expression>, Box<Expression>),
+    /// Logical negation of a boolean.
+    Not(Box<Expression>),
+    /// Conditional select `condition ? lhs : rhs`, the condition is a
+    /// boolean.
+    Select(Box<Expression>, Box<Expression>, Box<Expression>),
+    /// Fixed-point product of two decimals, rescaled and rounded as the
+    /// format configures.
+    FixedMul(Box<Expression>, Box<Expression>, FixedPoint),
+    /// Function call on its arguments, as many as the function's arity.
+    Call(Function, Vec<Expression>),
+}
+
+/// Expression implementation.
+impl Expression {
+    /// Evaluates the expression natively, outside of the circuit.
+    pub fn evaluate<F: FieldExt>(&self, variables: &[F]) -> F {
+        match self {
+            Expression::Constant(constant) => F::from(*constant),
+            Expression::Element(value) => field::from_biguint(value),
+            Expression::Variable(index) => variables[*index],
+            // the parser guarantees a constant modulus of at least one,
+            // leaves past u128 are rejected by the circuit
+            Expression::Binary(Operator::Mod, lhs, modulus) => {
+                let leaf = |e: &Expression| Some(e.evaluate::<F>(variables).get_lower_128());
+                let modulus = modulus.evaluate::<F>(variables).get_lower_128();
+                F::from_u128(lhs.evaluate_mod(modulus, &leaf).unwrap_or(0))
+            }
+            Expression::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.evaluate(variables);
+                let rhs = rhs.evaluate(variables);
+
+                match operator {
+                    Operator::Add => lhs + rhs,
+                    Operator::Sub => lhs - rhs,
+                    Operator::Mul => lhs * rhs,
+                    // zero has no inverse, a zero divisor is rejected by the
+                    // circuit
+                    Operator::Div => lhs * rhs.invert().unwrap_or(F::zero()),
+                    // exponents are u64, a private exponent that does not fit
+                    // is rejected by the circuit
+                    Operator::Pow => lhs.pow(&[rhs.get_lower_128() as u64, 0, 0, 0]),
+                    // orderings compare as u64, operands that do not fit are
+                    // rejected by the circuit
+                    Operator::Lt => F::from((lhs.get_lower_128() < rhs.get_lower_128()) as u64),
+                    Operator::Le => F::from((lhs.get_lower_128() <= rhs.get_lower_128()) as u64),
+                    Operator::Gt => F::from((lhs.get_lower_128() > rhs.get_lower_128()) as u64),
+                    Operator::Ge => F::from((lhs.get_lower_128() >= rhs.get_lower_128()) as u64),
+                    Operator::Eq => F::from((lhs == rhs) as u64),
+                    Operator::Ne => F::from((lhs != rhs) as u64),
+                    // booleans are `0` or `1`, other operands are rejected by
+                    // the circuit
+                    Operator::And => lhs * rhs,
+                    Operator::Or => lhs + rhs - lhs * rhs,
+                    // bitwise operators are over u64, operands that do not
+                    // fit are rejected by the circuit
+                    Operator::BitAnd => {
+                        F::from(lhs.get_lower_128() as u64 & rhs.get_lower_128() as u64)
+                    }
+                    Operator::BitOr => {
+                        F::from(lhs.get_lower_128() as u64 | rhs.get_lower_128() as u64)
+                    }
+                    Operator::BitXor => {
+                        F::from(lhs.get_lower_128() as u64 ^ rhs.get_lower_128() as u64)
+                    }
+                    // the parser guarantees a constant shift below 64
+                    Operator::Shl => F::from((lhs.get_lower_128() as u64) << rhs.get_lower_128()),
+                    Operator::Shr => F::from((lhs.get_lower_128() as u64) >> rhs.get_lower_128()),
+                    // wrapping operators are over u64 like bitwise operators
+                    Operator::WrappingAdd => F::from(
+                        (lhs.get_lower_128() as u64).wrapping_add(rhs.get_lower_128() as u64),
+                    ),
+                    Operator::WrappingSub => F::from(
+                        (lhs.get_lower_128() as u64).wrapping_sub(rhs.get_lower_128() as u64),
+                    ),
+                    Operator::WrappingMul => F::from(
+                        (lhs.get_lower_128() as u64).wrapping_mul(rhs.get_lower_128() as u64),
+                    ),
+                    Operator::SaturatingAdd => F::from(
+                        (lhs.get_lower_128() as u64).saturating_add(rhs.get_lower_128() as u64),
+                    ),
+                    Operator::SaturatingSub => F::from(
+                        (lhs.get_lower_128() as u64).saturating_sub(rhs.get_lower_128() as u64),
+                    ),
+                    Operator::SaturatingMul => F::from(
+                        (lhs.get_lower_128() as u64).saturating_mul(rhs.get_lower_128() as u64),
+                    ),
+                    // a modulus is evaluated under it above, so this only
+                    // reduces a plain value
+                    Operator::Mod => F::from_u128(lhs.get_lower_128() % rhs.get_lower_128()),
+                }
+            }
+            Expression::Not(e) => F::one() - e.evaluate(variables),
+            Expression::Select(condition, lhs, rhs) => {
+                let condition = condition.evaluate(variables);
+                condition * lhs.evaluate(variables)
+                    + (F::one() - condition) * rhs.evaluate(variables)
+            }
+            // operands are u64, operands that do not fit are rejected by the
+            // circuit
+            Expression::FixedMul(lhs, rhs, format) => F::from_u128(format.mul(
+                lhs.evaluate::<F>(variables).get_lower_128() as u64,
+                rhs.evaluate::<F>(variables).get_lower_128() as u64,
+            )),
+            // the magnitude of a signed argument, a magnitude past u64 is
+            // rejected by the circuit
+            Expression::Call(Function::Abs, args) => {
+                let a = args[0].evaluate::<F>(variables);
+                field::to_signed(&a).map_or(a, |a| F::from_u128(a.unsigned_abs()))
+            }
+            Expression::Call(function, args) => {
+                // arguments are u64, arguments that do not fit are rejected
+                // by the circuit
+                let args: Vec<Operand> = args
+                    .iter()
+                    .map(|arg| arg.evaluate::<F>(variables).get_lower_128() as Operand)
+                    .collect();
+                let (a, b) = (args[0], args.get(1).copied().unwrap_or(0));
+
+                match function {
+                    Function::Sqrt => F::from(a.isqrt()),
+                    Function::Gcd => F::from(gcd(a, b)),
+                    // a least common multiple past u64 is rejected before
+                    // proving
+                    Function::Lcm => lcm(a, b).map_or(F::zero(), F::from),
+                    Function::Abs => F::from(a),
+                    Function::Min => F::from(args.iter().copied().min().unwrap_or(a)),
+                    Function::Max => F::from(args.iter().copied().max().unwrap_or(a)),
+                }
+            }
+        }
+    }
+
+    /// Returns true if the expression references a private variable.
+    pub fn is_private(&self) -> bool {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) => false,
+            Expression::Variable(_) => true,
+            Expression::Binary(_, lhs, rhs) | Expression::FixedMul(lhs, rhs, _) => {
+                lhs.is_private() || rhs.is_private()
+            }
+            Expression::Not(e) => e.is_private(),
+            Expression::Select(condition, lhs, rhs) => {
+                condition.is_private() || lhs.is_private() || rhs.is_private()
+            }
+            Expression::Call(_, args) => args.iter().any(Expression::is_private),
+        }
+    }
+
+    /// Returns true if the expression is a constant literal.
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Expression::Constant(_) | Expression::Element(_))
+    }
+
+    /// Returns the constant of a literal, a field element constant if it
+    /// does not fit into a u64.
+    pub fn literal(value: BigUint) -> Expression {
+        match value.to_limbs(1) {
+            Some(limbs) => Expression::Constant(limbs[0]),
+            None => Expression::Element(value),
+        }
+    }
+
+    /// Folds a constant expression into its u64 value.
+    /// Returns `None` if the expression references a variable or overflows.
+    pub fn constant_value(&self) -> Option<Operand> {
+        match self {
+            Expression::Constant(constant) => Some(*constant),
+            // field element constants are past u64 by construction
+            Expression::Element(_) | Expression::Variable(_) => None,
+            Expression::Binary(Operator::Mod, lhs, modulus) => {
+                let leaf = |e: &Expression| e.constant_value().map(u128::from);
+                let modulus = modulus.constant_value().filter(|m| *m > 0)?;
+                lhs.evaluate_mod(modulus as u128, &leaf)
+                    .map(|value| value as Operand)
+            }
+            Expression::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.constant_value()?;
+                let rhs = rhs.constant_value()?;
+
+                match operator {
+                    Operator::Add => lhs.checked_add(rhs),
+                    Operator::Sub => lhs.checked_sub(rhs),
+                    Operator::Mul => lhs.checked_mul(rhs),
+                    // only exact quotients agree with the field inverse
+                    Operator::Div => (lhs.checked_rem(rhs)? == 0).then_some(lhs / rhs),
+                    Operator::Pow => lhs.checked_pow(u32::try_from(rhs).ok()?),
+                    Operator::Lt => Some((lhs < rhs) as Operand),
+                    Operator::Le => Some((lhs <= rhs) as Operand),
+                    Operator::Gt => Some((lhs > rhs) as Operand),
+                    Operator::Ge => Some((lhs >= rhs) as Operand),
+                    Operator::Eq => Some((lhs == rhs) as Operand),
+                    Operator::Ne => Some((lhs != rhs) as Operand),
+                    Operator::And => (lhs <= 1 && rhs <= 1).then_some(lhs & rhs),
+                    Operator::Or => (lhs <= 1 && rhs <= 1).then_some(lhs | rhs),
+                    Operator::BitAnd => Some(lhs & rhs),
+                    Operator::BitOr => Some(lhs | rhs),
+                    Operator::BitXor => Some(lhs ^ rhs),
+                    Operator::Shl => lhs.checked_shl(u32::try_from(rhs).ok()?),
+                    Operator::Shr => lhs.checked_shr(u32::try_from(rhs).ok()?),
+                    Operator::WrappingAdd => Some(lhs.wrapping_add(rhs)),
+                    Operator::WrappingSub => Some(lhs.wrapping_sub(rhs)),
+                    Operator::WrappingMul => Some(lhs.wrapping_mul(rhs)),
+                    Operator::SaturatingAdd => Some(lhs.saturating_add(rhs)),
+                    Operator::SaturatingSub => Some(lhs.saturating_sub(rhs)),
+                    Operator::SaturatingMul => Some(lhs.saturating_mul(rhs)),
+                    // a modulus is folded under it above, so this only
+                    // reduces a plain value
+                    Operator::Mod => lhs.checked_rem(rhs),
+                }
+            }
+            Expression::Not(e) => match e.constant_value()? {
+                0 => Some(1),
+                1 => Some(0),
+                _ => None,
+            },
+            Expression::Select(condition, lhs, rhs) => match condition.constant_value()? {
+                0 => rhs.constant_value(),
+                1 => lhs.constant_value(),
+                _ => None,
+            },
+            Expression::FixedMul(lhs, rhs, format) => {
+                Operand::try_from(format.mul(lhs.constant_value()?, rhs.constant_value()?)).ok()
+            }
+            Expression::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(Expression::constant_value)
+                    .collect::<Option<Vec<Operand>>>()?;
+
+                match function {
+                    Function::Sqrt => Some(args[0].isqrt()),
+                    Function::Gcd => Some(gcd(args[0], args[1])),
+                    Function::Lcm => lcm(args[0], args[1]),
+                    // constants are never negative
+                    Function::Abs => Some(args[0]),
+                    Function::Min => args.iter().copied().min(),
+                    Function::Max => args.iter().copied().max(),
+                }
+            }
+        }
+    }
+
+    /// Returns true if any operator of the expression satisfies the
+    /// predicate.
+    pub fn any_operator(&self, predicate: &impl Fn(&Operator) -> bool) -> bool {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) | Expression::Variable(_) => false,
+            Expression::Binary(operator, lhs, rhs) => {
+                predicate(operator) || lhs.any_operator(predicate) || rhs.any_operator(predicate)
+            }
+            Expression::Not(e) => e.any_operator(predicate),
+            Expression::FixedMul(lhs, rhs, _) => {
+                lhs.any_operator(predicate) || rhs.any_operator(predicate)
+            }
+            Expression::Select(condition, lhs, rhs) => {
+                condition.any_operator(predicate)
+                    || lhs.any_operator(predicate)
+                    || rhs.any_operator(predicate)
+            }
+            Expression::Call(_, args) => args.iter().any(|arg| arg.any_operator(predicate)),
+        }
+    }
+
+    /// Returns true if any function call of the expression satisfies the
+    /// predicate.
+    pub fn any_function(&self, predicate: &impl Fn(&Function) -> bool) -> bool {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) | Expression::Variable(_) => false,
+            Expression::Binary(_, lhs, rhs) | Expression::FixedMul(lhs, rhs, _) => {
+                lhs.any_function(predicate) || rhs.any_function(predicate)
+            }
+            Expression::Not(e) => e.any_function(predicate),
+            Expression::Select(condition, lhs, rhs) => {
+                condition.any_function(predicate)
+                    || lhs.any_function(predicate)
+                    || rhs.any_function(predicate)
+            }
+            Expression::Call(function, args) => {
+                predicate(function) || args.iter().any(|arg| arg.any_function(predicate))
+            }
+        }
+    }
+
+    /// Evaluates the expression modulo `m`, the left hand side of a `mod m`.
+    /// Additions, subtractions, multiplications and public powers are
+    /// reduced after every step, anything else is a leaf, evaluated by `leaf`
+    /// and then reduced. Returns `None` if a leaf does.
+    pub fn evaluate_mod(
+        &self,
+        modulus: u128,
+        leaf: &impl Fn(&Expression) -> Option<u128>,
+    ) -> Option<u128> {
+        match self {
+            Expression::Binary(
+                operator @ (Operator::Add | Operator::Sub | Operator::Mul),
+                lhs,
+                rhs,
+            ) => {
+                let lhs = lhs.evaluate_mod(modulus, leaf)?;
+                let rhs = rhs.evaluate_mod(modulus, leaf)?;
+
+                // reduced operands are below `m <= 2^64`, so nothing
+                // overflows
+                match operator {
+                    Operator::Add => Some((lhs + rhs) % modulus),
+                    Operator::Sub => Some((lhs + modulus - rhs) % modulus),
+                    _ => Some(lhs * rhs % modulus),
+                }
+            }
+            Expression::Binary(Operator::Pow, base, exponent) if exponent.is_constant() => {
+                let base = base.evaluate_mod(modulus, leaf)?;
+                let exponent = exponent.constant_value()?;
+
+                // square and multiply, reducing after every step
+                let bits = 64 - exponent.leading_zeros();
+                Some((0..bits).rev().fold(1 % modulus, |acc, bit| {
+                    let acc = acc * acc % modulus;
+                    match exponent >> bit & 1 {
+                        1 => acc * base % modulus,
+                        _ => acc,
+                    }
+                }))
+            }
+            // a field element constant may exceed any leaf, so it is
+            // reduced natively
+            Expression::Element(value) => Some(value.clone().div_rem_small(modulus as u64) as u128),
+            e => leaf(e).map(|value| value % modulus),
+        }
+    }
+
+    /// Returns true if every exponent under a modulus is public, so the left
+    /// hand side of a `mod m` can be reduced after every step.
+    fn has_public_modular_exponents(&self) -> bool {
+        match self {
+            Expression::Binary(Operator::Add | Operator::Sub | Operator::Mul, lhs, rhs) => {
+                lhs.has_public_modular_exponents() && rhs.has_public_modular_exponents()
+            }
+            Expression::Binary(Operator::Pow, base, exponent) => {
+                exponent.is_constant() && base.has_public_modular_exponents()
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns true if a least common multiple of the expression does not
+    /// fit into a u64. The circuit would only reject it at verification, so
+    /// it is detected natively before proving.
+    pub fn lcm_overflows<F: FieldExt>(&self, variables: &[F]) -> bool {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) | Expression::Variable(_) => false,
+            Expression::Binary(_, lhs, rhs) | Expression::FixedMul(lhs, rhs, _) => {
+                lhs.lcm_overflows(variables) || rhs.lcm_overflows(variables)
+            }
+            Expression::Not(e) => e.lcm_overflows(variables),
+            Expression::Select(condition, lhs, rhs) => {
+                condition.lcm_overflows(variables)
+                    || lhs.lcm_overflows(variables)
+                    || rhs.lcm_overflows(variables)
+            }
+            Expression::Call(function, args) => {
+                let overflows = matches!(function, Function::Lcm) && {
+                    let a = args[0].evaluate::<F>(variables).get_lower_128() as Operand;
+                    let b = args[1].evaluate::<F>(variables).get_lower_128() as Operand;
+                    lcm(a, b).is_none()
+                };
+
+                overflows || args.iter().any(|arg| arg.lcm_overflows(variables))
+            }
+        }
+    }
+
+    /// Returns true if a field element constant is an operand of a u64
+    /// operator or function. Their chips only range check private inputs, as
+    /// u64 constants fit by construction.
+    pub fn has_element_operand(&self) -> bool {
+        // field operators take any field element, and the left hand side of
+        // a `mod m` is reduced natively
+        let is_element = |e: &Expression| matches!(e, Expression::Element(_));
+        match self {
+            Expression::Constant(_) | Expression::Element(_) | Expression::Variable(_) => false,
+            Expression::Binary(Operator::Mod, lhs, _) => lhs.has_element_operand(),
+            Expression::Binary(operator, lhs, rhs) => {
+                (!operator.is_field() && (is_element(lhs) || is_element(rhs)))
+                    || lhs.has_element_operand()
+                    || rhs.has_element_operand()
+            }
+            Expression::FixedMul(lhs, rhs, _) => {
+                is_element(lhs)
+                    || is_element(rhs)
+                    || lhs.has_element_operand()
+                    || rhs.has_element_operand()
+            }
+            Expression::Not(e) => is_element(e) || e.has_element_operand(),
+            Expression::Select(condition, lhs, rhs) => {
+                is_element(condition)
+                    || condition.has_element_operand()
+                    || lhs.has_element_operand()
+                    || rhs.has_element_operand()
+            }
+            Expression::Call(_, args) => args
+                .iter()
+                .any(|arg| is_element(arg) || arg.has_element_operand()),
+        }
+    }
+
+    /// Returns true if the expression only consists of constants, variables
+    /// and binary operators.
+    pub fn is_arithmetic(&self) -> bool {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) | Expression::Variable(_) => true,
+            Expression::Binary(_, lhs, rhs) => lhs.is_arithmetic() && rhs.is_arithmetic(),
+            _ => false,
+        }
+    }
+
+    /// Counts the uses of each variable, indexed like the variable list.
+    pub fn count_uses(&self, uses: &mut [usize]) {
+        match self {
+            Expression::Constant(_) | Expression::Element(_) => (),
+            Expression::Variable(index) => uses[*index] += 1,
+            Expression::Binary(_, lhs, rhs) | Expression::FixedMul(lhs, rhs, _) => {
+                lhs.count_uses(uses);
+                rhs.count_uses(uses);
+            }
+            Expression::Not(e) => e.count_uses(uses),
+            Expression::Select(condition, lhs, rhs) => {
+                condition.count_uses(uses);
+                lhs.count_uses(uses);
+                rhs.count_uses(uses);
+            }
+            Expression::Call(_, args) => args.iter().for_each(|arg| arg.count_uses(uses)),
+        }
+    }
+}
+
+/// Returns the greatest common divisor of two u64, `gcd(0, 0)` is `0`.
+fn gcd(a: Operand, b: Operand) -> Operand {
+    rational::extended_gcd(a as u128, b as u128).0 as Operand
+}
+
+/// Returns the least common multiple of two u64.
+/// Returns `None` if it does not fit into a u64.
+fn lcm(a: Operand, b: Operand) -> Option<Operand> {
+    match gcd(a, b) {
+        0 => Some(0),
+        g => (a / g).checked_mul(b),
+    }
+}
+
+/// Big integer expression syntax tree, restricted to `+` and `*`.
+#[derive(Clone, Debug)]
+pub enum BigExpression {
+    /// Numeric literal of any size, baked into the circuit as constant limbs.
+    Constant(BigUint),
+    /// Private variable, indexes into the parsed variable list.
+    Variable(usize),
+    /// Binary operation, either `Operator::Add` or `Operator::Mul`.
+    Binary(Operator, Box<BigExpression>, Box<BigExpression>),
+}
+
+/// Big integer expression implementation.
+impl BigExpression {
+    /// Evaluates the expression over the integers, given the values of its
+    /// variables.
+    pub fn evaluate(&self, variables: &[BigUint]) -> BigUint {
+        match self {
+            BigExpression::Constant(constant) => constant.clone(),
+            BigExpression::Variable(index) => variables[*index].clone(),
+            BigExpression::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.evaluate(variables);
+                let rhs = rhs.evaluate(variables);
+                match operator {
+                    Operator::Mul => &lhs * &rhs,
+                    // the parser only accepts `+` and `*`
+                    _ => &lhs + &rhs,
+                }
+            }
+        }
+    }
+
+    /// Returns the number of limbs of the result, given the number of limbs
+    /// of each variable. Sums take one limb more than their widest operand,
+    /// products as many as both operands together.
+    pub fn limbs(&self, variable_limbs: usize) -> usize {
+        match self {
+            BigExpression::Constant(constant) => constant.limbs().len().max(1),
+            BigExpression::Variable(_) => variable_limbs,
+            BigExpression::Binary(operator, lhs, rhs) => {
+                let lhs = lhs.limbs(variable_limbs);
+                let rhs = rhs.limbs(variable_limbs);
+                match operator {
+                    Operator::Mul => lhs + rhs,
+                    _ => lhs.max(rhs) + 1,
+                }
+            }
+        }
+    }
+}
+
+/// Lexical token.
+#[derive(Clone)]
+enum Token {
+    /// Numeric operand, parsed by the parser of each expression kind.
+    Operand(String),
+    /// Private variable name, without the `?` prefix.
+    Variable(String),
+    /// Binary operator.
+    Operator(Operator),
+    /// Logical negation `!`.
+    Not,
+    /// Conditional `?`, not followed by a variable name.
+    Question,
+    /// Conditional `:`.
+    Colon,
+    /// Function argument separator `,`.
+    Comma,
+    /// Function name, followed by its parenthesized arguments.
+    Function(Function),
+    /// Opening parenthesis.
+    LeftParen,
+    /// Closing parenthesis.
+    RightParen,
+}
+
+/// Splits a string slice into tokens.
+/// Operators are matched greedily, so the longest valid operator wins.
+fn tokenize(input: &str) -> Result<Vec<Token>, ParserError> {
+    let mut tokens = Vec::new();
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        // skip whitespace
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+            continue;
+        }
+
+        // parse parentheses
+        if c == '(' || c == ')' {
+            tokens.push(match c {
+                '(' => Token::LeftParen,
+                _ => Token::RightParen,
+            });
+            rest = &rest[1..];
+            continue;
+        }
+
+        // parse into variable, names start with a letter or underscore, so a
+        // `?` that is not followed by a name is the conditional operator
+        if c == '?' {
+            let name = &rest[1..];
+            let end = match name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                true => name
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(name.len()),
+                false => 0,
+            };
+            tokens.push(match end {
+                0 => Token::Question,
+                _ => Token::Variable(name[..end].to_string()),
+            });
+            rest = &name[end..];
+            continue;
+        }
+
+        // parse the conditional and argument separators
+        if c == ':' || c == ',' {
+            tokens.push(match c {
+                ':' => Token::Colon,
+                _ => Token::Comma,
+            });
+            rest = &rest[1..];
+            continue;
+        }
+
+        // parse into operand or bubble up error, decimal points are part of
+        // the operand
+        if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '.')
+                .unwrap_or(rest.len());
+            tokens.push(Token::Operand(rest[..end].to_string()));
+            rest = &rest[end..];
+            continue;
+        }
+
+        // parse into a function name, other words like `xor` are operators
+        if c.is_ascii_alphabetic() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            if let Ok(function) = Function::from_token(&rest[..end]) {
+                tokens.push(Token::Function(function));
+                rest = &rest[end..];
+                continue;
+            }
+        }
+
+        // parse into the longest matching operator, a lone `!` is logical
+        // negation, or bubble up error
+        let operator = (1..=MAX_OPERATOR_LEN).rev().find_map(|len| {
+            let token = rest.get(..len)?;
+            Operator::from_token(token)
+                .ok()
+                .map(|operator| (operator, len))
+        });
+        match operator {
+            Some((operator, len)) => {
+                tokens.push(Token::Operator(operator));
+                rest = &rest[len..];
+            }
+            None if c == '!' => {
+                tokens.push(Token::Not);
+                rest = &rest[1..];
+            }
+            None => return Err(ParserError::InvalidOperator),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Literal conversion of a parser mode.
+type Literal = Box<dyn Fn(&str) -> Result<Expression, ParserError>>;
+
+/// Expression parser.
+///
+/// Precedence climbing parser, operators with a higher `Operator::precedence`
+/// bind tighter and operators of equal precedence associate to the left,
+/// unless they are `Operator::is_right_associative`.
+struct Parser {
+    /// Remaining tokens.
+    tokens: Peekable<IntoIter<Token>>,
+    /// Variable names in the order they first appear.
+    variables: Vec<String>,
+    /// Converts a numeric literal into an expression.
+    literal: Literal,
+    /// Fixed-point format of decimal mode, products are rescaled.
+    fixed: Option<FixedPoint>,
+    /// Whether unary minus is supported, only in modes whose values may be
+    /// negative.
+    signed: bool,
+}
+
+/// Parser implementation.
+impl Parser {
+    /// Parses a conditional `condition ? lhs : rhs`, which binds looser than
+    /// any binary operator and associates to the right.
+    fn conditional(&mut self) -> Result<Expression, ParserError> {
+        let condition = self.expression(0)?;
+
+        if !matches!(self.tokens.peek(), Some(Token::Question)) {
+            return Ok(condition);
+        }
+        self.tokens.next();
+
+        let lhs = self.conditional()?;
+        if !matches!(self.tokens.next(), Some(Token::Colon)) {
+            return Err(ParserError::InvalidConditional);
+        }
+        let rhs = self.conditional()?;
+
+        Ok(Expression::Select(
+            Box::new(condition),
+            Box::new(lhs),
+            Box::new(rhs),
+        ))
+    }
+
+    /// Parses an expression whose operators bind at least as tight as
+    /// `min_precedence`.
+    fn expression(&mut self, min_precedence: u8) -> Result<Expression, ParserError> {
+        let mut lhs = self.primary()?;
+
+        while let Some(Token::Operator(operator)) = self.tokens.peek() {
+            let operator = *operator;
+            if operator.precedence() < min_precedence {
+                break;
+            }
+            self.tokens.next();
+
+            let rhs = match operator.is_right_associative() {
+                true => self.expression(operator.precedence())?,
+                false => self.expression(operator.precedence() + 1)?,
+            };
+
+            // public exponents must fold into a constant, private exponents
+            // are decomposed into bits by the circuit
+            let rhs = match operator {
+                Operator::Pow if !rhs.is_private() => rhs
+                    .constant_value()
+                    .map(Expression::Constant)
+                    .ok_or(ParserError::InvalidExponent)?,
+                // shifts are public constants below the operand width
+                Operator::Shl | Operator::Shr => rhs
+                    .constant_value()
+                    .filter(|n| *n < BITS as Operand)
+                    .map(Expression::Constant)
+                    .ok_or(ParserError::InvalidShift)?,
+                // the modulus is a public constant of at least one, and the
+                // reduced expression has no private exponents
+                Operator::Mod => rhs
+                    .constant_value()
+                    .filter(|m| *m > 0 && lhs.has_public_modular_exponents())
+                    .map(Expression::Constant)
+                    .ok_or(ParserError::InvalidModulus)?,
+                _ => rhs,
+            };
+
+            lhs = match (operator, self.fixed) {
+                (Operator::Mul, Some(format)) => {
+                    Expression::FixedMul(Box::new(lhs), Box::new(rhs), format)
+                }
+                _ => Expression::Binary(operator, Box::new(lhs), Box::new(rhs)),
+            };
+        }
+
+        Ok(lhs)
+    }
+
+    /// Parses the parenthesized, comma separated arguments of a function
+    /// call, as many as the function's arity allows.
+    fn call(&mut self, function: Function) -> Result<Expression, ParserError> {
+        if !matches!(self.tokens.next(), Some(Token::LeftParen)) {
+            return Err(ParserError::InvalidFunction);
+        }
+
+        let mut args = vec![self.conditional()?];
+        while matches!(self.tokens.peek(), Some(Token::Comma)) {
+            self.tokens.next();
+            args.push(self.conditional()?);
+        }
+
+        if !matches!(self.tokens.next(), Some(Token::RightParen))
+            || !function.arity().contains(&args.len())
+        {
+            return Err(ParserError::InvalidFunction);
+        }
+
+        // a public least common multiple must fit into a u64
+        if let (Function::Lcm, Some(a), Some(b)) = (
+            function,
+            args[0].constant_value(),
+            args.get(1).and_then(Expression::constant_value),
+        ) {
+            lcm(a, b).ok_or(ParserError::InvalidLcm)?;
+        }
+
+        Ok(Expression::Call(function, args))
+    }
+
+    /// Parses an operand, a variable or a parenthesized expression.
+    fn primary(&mut self) -> Result<Expression, ParserError> {
+        match self.tokens.next() {
+            Some(Token::Operand(operand)) => (self.literal)(&operand),
+            Some(Token::Variable(name)) => Ok(Expression::Variable(variable_index(
+                &mut self.variables,
+                name,
+            ))),
+            Some(Token::LeftParen) => {
+                let expression = self.conditional()?;
+                match self.tokens.next() {
+                    Some(Token::RightParen) => Ok(expression),
+                    _ => Err(ParserError::UnbalancedParentheses),
+                }
+            }
+            Some(Token::RightParen) => Err(ParserError::UnbalancedParentheses),
+            // `!` binds tighter than any binary operator
+            Some(Token::Not) => Ok(Expression::Not(Box::new(self.primary()?))),
+            Some(Token::Function(function)) => self.call(function),
+            // integers and decimals are unsigned, a field negation would
+            // only pass for a huge value
+            Some(Token::Operator(Operator::Sub)) if !self.signed => {
+                Err(ParserError::InvalidNegation)
+            }
+            // unary minus `-a` is `0 - a`, negating in the field, or the
+            // fraction in rational mode. It binds tighter than any binary
+            // operator but `^`, so `-a ^ 2` is `-(a ^ 2)`
+            Some(Token::Operator(Operator::Sub)) => {
+                // a negated literal that is not raised to a power is a
+                // single constant in modes whose literals may be negative,
+                // e.g. the additive inverse in field mode
+                let mut lookahead = self.tokens.clone();
+                if let (Some(Token::Operand(operand)), next) = (lookahead.next(), lookahead.next())
+                {
+                    if !matches!(next, Some(Token::Operator(Operator::Pow))) {
+                        if let Ok(negated) = (self.literal)(&format!("-{}", operand)) {
+                            self.tokens.next();
+                            return Ok(negated);
+                        }
+                    }
+                }
+
+                let operand = self.expression(Operator::Pow.precedence())?;
+                Ok(Expression::Binary(
+                    Operator::Sub,
+                    Box::new(Expression::Constant(0)),
+                    Box::new(operand),
+                ))
+            }
+            // a `?` where an operand is expected is a variable without a name
+            Some(Token::Question) => Err(ParserError::InvalidVariable),
+            Some(Token::Operator(_)) | Some(Token::Colon) | Some(Token::Comma) => {
+                Err(ParserError::InvalidOperand)
+            }
+            None => Err(ParserError::NotEnoughInputs),
+        }
+    }
+}
+
+/// Big integer expression parser.
+///
+/// Precedence climbing parser like `Parser`, over `+`, `*`, parentheses,
+/// literals of any size and variables.
+struct BigParser {
+    /// Remaining tokens.
+    tokens: Peekable<IntoIter<Token>>,
+    /// Variable names in the order they first appear.
+    variables: Vec<String>,
+}
+
+/// Big integer parser implementation.
+impl BigParser {
+    /// Parses an expression whose operators bind at least as tight as
+    /// `min_precedence`.
+    fn expression(&mut self, min_precedence: u8) -> Result<BigExpression, ParserError> {
+        let mut lhs = self.primary()?;
+
+        while let Some(Token::Operator(operator)) = self.tokens.peek() {
+            let operator = *operator;
+            if !matches!(operator, Operator::Add | Operator::Mul) {
+                return Err(ParserError::InvalidOperator);
+            }
+            if operator.precedence() < min_precedence {
+                break;
+            }
+            self.tokens.next();
+
+            let rhs = self.expression(operator.precedence() + 1)?;
+            lhs = BigExpression::Binary(operator, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    /// Parses an operand, a variable or a parenthesized expression.
+    fn primary(&mut self) -> Result<BigExpression, ParserError> {
+        match self.tokens.next() {
+            Some(Token::Operand(operand)) => BigUint::from_decimal(&operand)
+                .map(BigExpression::Constant)
+                .ok_or(ParserError::InvalidOperand),
+            Some(Token::Variable(name)) => Ok(BigExpression::Variable(variable_index(
+                &mut self.variables,
+                name,
+            ))),
+            Some(Token::LeftParen) => {
+                let expression = self.expression(0)?;
+                match self.tokens.next() {
+                    Some(Token::RightParen) => Ok(expression),
+                    _ => Err(ParserError::UnbalancedParentheses),
+                }
+            }
+            Some(Token::RightParen) => Err(ParserError::UnbalancedParentheses),
+            Some(Token::Question) => Err(ParserError::InvalidVariable),
+            Some(Token::Not) => Err(ParserError::InvalidOperator),
+            // functions are over u64
+            Some(Token::Function(_)) => Err(ParserError::InvalidFunction),
+            Some(Token::Operator(_)) | Some(Token::Colon) | Some(Token::Comma) => {
+                Err(ParserError::InvalidOperand)
+            }
+            None => Err(ParserError::NotEnoughInputs),
+        }
+    }
+}
+
+/// Returns the index of a variable name, repeated uses of a name refer to the
+/// same variable.
+fn variable_index(variables: &mut Vec<String>, name: String) -> usize {
+    match variables.iter().position(|v| *v == name) {
+        Some(index) => index,
+        None => {
+            variables.push(name);
+            variables.len() - 1
+        }
+    }
+}
+
+/// Parses a string slice into a big integer Expression and its variable
+/// names. Only `+` and `*` are supported, literals may be of any size.
+pub fn parse_big(input: &str) -> Result<(BigExpression, Vec<String>), ParserError> {
+    let mut parser = BigParser {
+        tokens: tokenize(input)?.into_iter().peekable(),
+        variables: Vec::new(),
+    };
+
+    let expression = parser.expression(0)?;
+
+    // if there are more tokens remaining, something went wrong, so we
+    // bubble up an error about it
+    match parser.tokens.next() {
+        None => Ok((expression, parser.variables)),
+        Some(Token::RightParen) => Err(ParserError::UnbalancedParentheses),
+        Some(_) => Err(ParserError::TooManyInputs),
+    }
+}
+
+/// Parses a string slice into an Expression and its variable names.
+/// Literals are u64, like the values of statements.
+pub fn parse(input: &str) -> Result<(Expression, Vec<String>), ParserError> {
+    parse_integer_with(input, integer_literal)
+}
+
+/// Parses a string slice into an integer Expression and its variable names.
+/// Literals are integers below the field modulus, a literal past u64 is a
+/// field element constant.
+pub fn parse_integer<F: FieldExt>(input: &str) -> Result<(Expression, Vec<String>), ParserError> {
+    parse_integer_with(input, wide_literal::<F>)
+}
+
+/// Parses a string slice into an integer Expression with the given literal
+/// conversion.
+fn parse_integer_with(
+    input: &str,
+    literal: impl Fn(&str) -> Result<Expression, ParserError> + 'static,
+) -> Result<(Expression, Vec<String>), ParserError> {
+    let (expression, variables) = parse_with(input, literal, None, false)?;
+
+    // division is only defined over the field, and literals past u64 only
+    // reach field operators
+    match expression.any_operator(&|operator| matches!(operator, Operator::Div)) {
+        true => Err(ParserError::InvalidOperator),
+        false if expression.has_element_operand() => Err(ParserError::InvalidOperand),
+        false => Ok((expression, variables)),
+    }
+}
+
+/// Parses a string slice into a field Expression and its variable names.
+/// Literals are decimal or `0x` hex field elements and only
+/// `Operator::is_field` operators are supported.
+pub fn parse_field<F: FieldExt>(input: &str) -> Result<(Expression, Vec<String>), ParserError> {
+    let (expression, variables) = parse_with(input, field_literal::<F>, None, true)?;
+
+    // functions are over u64
+    match expression.any_operator(&|operator| !operator.is_field()) {
+        true => Err(ParserError::InvalidOperator),
+        false if expression.any_function(&|_| true) => Err(ParserError::InvalidFunction),
+        false => Ok((expression, variables)),
+    }
+}
+
+/// Parses a string slice into a fixed-point Expression and its variable
+/// names. Literals are decimals, e.g. `12.50`, and only `+`, `-` and `*` are
+/// supported, products are rescaled in the given format.
+pub fn parse_fixed(
+    input: &str,
+    format: FixedPoint,
+) -> Result<(Expression, Vec<String>), ParserError> {
+    let literal = move |token: &str| format.parse_literal(token).map(Expression::Constant);
+    let (expression, variables) = parse_with(input, literal, Some(format), false)?;
+
+    // functions are over plain u64
+    match expression.any_operator(&|operator| !matches!(operator, Operator::Add | Operator::Sub)) {
+        true => Err(ParserError::InvalidOperator),
+        false if expression.any_function(&|_| true) => Err(ParserError::InvalidFunction),
+        false => Ok((expression, variables)),
+    }
+}
+
+/// Parses a string slice into a rational Expression and its variable names.
+/// Literals are i64 integers and only `+`, `-`, `*` and `/` are supported,
+/// over exact fractions.
+pub fn parse_rational(input: &str) -> Result<(Expression, Vec<String>), ParserError> {
+    let (expression, variables) = parse_with(input, rational_literal, None, true)?;
+
+    match expression.any_operator(&|operator| {
+        !matches!(
+            operator,
+            Operator::Add | Operator::Sub | Operator::Mul | Operator::Div
+        )
+    }) {
+        true => Err(ParserError::InvalidOperator),
+        false if expression.any_function(&|_| true) => Err(ParserError::InvalidFunction),
+        false if !expression.is_arithmetic() => Err(ParserError::InvalidOperator),
+        false => Ok((expression, variables)),
+    }
+}
+
+/// Parses a string slice with the given literal conversion and fixed-point
+/// format, unary minus only if the mode is signed.
+fn parse_with(
+    input: &str,
+    literal: impl Fn(&str) -> Result<Expression, ParserError> + 'static,
+    fixed: Option<FixedPoint>,
+    signed: bool,
+) -> Result<(Expression, Vec<String>), ParserError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?.into_iter().peekable(),
+        variables: Vec::new(),
+        literal: Box::new(literal),
+        fixed,
+        signed,
+    };
+
+    let expression = parser.conditional()?;
+
+    // if there are more tokens remaining, something went wrong, so we
+    // bubble up an error about it
+    match parser.tokens.next() {
+        None => Ok((expression, parser.variables)),
+        Some(Token::RightParen) => Err(ParserError::UnbalancedParentheses),
+        Some(_) => Err(ParserError::TooManyInputs),
+    }
+}
+
+/// Converts a u64 literal into a constant.
+fn integer_literal(token: &str) -> Result<Expression, ParserError> {
+    Operand::from_token(token).map(Expression::Constant)
+}
+
+/// Converts a decimal integer literal below the field modulus into a
+/// constant.
+fn wide_literal<F: FieldExt>(token: &str) -> Result<Expression, ParserError> {
+    BigUint::from_decimal(token)
+        .filter(|value| *value < field::modulus::<F>())
+        .map(Expression::literal)
+        .ok_or(ParserError::InvalidOperand)
+}
+
+/// Converts an i64 literal into a constant, so it is a reduced fraction.
+fn rational_literal(token: &str) -> Result<Expression, ParserError> {
+    match Operand::from_token(token)? {
+        constant if constant <= i64::MAX as Operand => Ok(Expression::Constant(constant)),
+        _ => Err(ParserError::InvalidOperand),
+    }
+}
+
+/// Converts a field literal into a constant, a leading `-` means the additive
+/// inverse. A literal past u64 is a single field element constant.
+fn field_literal<F: FieldExt>(token: &str) -> Result<Expression, ParserError> {
+    let value = field::parse_element::<F>(token)?;
+    Ok(Expression::literal(field::to_biguint(&value)))
+}
+
+/// Parses a threshold statement `?x >= threshold` into the variable name and
+/// the public threshold. `threshold <= ?x` is accepted as well.
+pub fn parse_threshold(input: &str) -> Result<(String, Operand), ParserError> {
+    let (expression, mut variables) = parse(input)?;
+
+    // the statement compares the only variable to a constant threshold
+    let threshold = match expression {
+        Expression::Binary(Operator::Ge, lhs, threshold)
+        | Expression::Binary(Operator::Le, threshold, lhs)
+            if matches!(*lhs, Expression::Variable(_)) =>
+        {
+            threshold.constant_value()
+        }
+        _ => None,
+    };
+
+    match (threshold, variables.pop()) {
+        (Some(threshold), Some(name)) => Ok((name, threshold)),
+        _ => Err(ParserError::InvalidStatement),
+    }
+}
+
+/// Parses a factorization statement `?p * ?q == n` into the two variable
+/// names and the public `n`. `n == ?p * ?q` is accepted as well.
+pub fn parse_factorization(input: &str) -> Result<(String, String, Operand), ParserError> {
+    let (expression, variables) = parse(input)?;
+
+    // the statement compares the product of two distinct variables to a
+    // constant, on either side
+    let factors = |product: &Expression, n: &Expression| match product {
+        Expression::Binary(Operator::Mul, p, q) => match (p.as_ref(), q.as_ref()) {
+            (Expression::Variable(p), Expression::Variable(q)) if p != q => {
+                n.constant_value().map(|n| (*p, *q, n))
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    let statement = match &expression {
+        Expression::Binary(Operator::Eq, lhs, rhs) => factors(lhs, rhs).or(factors(rhs, lhs)),
+        _ => None,
+    };
+
+    match statement {
+        Some((p, q, n)) => Ok((variables[p].clone(), variables[q].clone(), n)),
+        None => Err(ParserError::InvalidStatement),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixed::Rounding, rational::Rational};
+    use halo2_proofs::pasta::{Fp, Fq};
+
+    fn evaluate(input: &str) -> Fp {
+        let (expression, variables) = parse(input).unwrap();
+        assert!(variables.is_empty());
+        expression.evaluate(&[])
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(evaluate("2 + 3 * 4"), Fp::from(14));
+        assert_eq!(evaluate("(2 + 3) * 4"), Fp::from(20));
+        assert_eq!(evaluate("10 - 4 - 3"), Fp::from(3));
+        assert_eq!(evaluate("2*3+4*5"), Fp::from(26));
+        assert_eq!(evaluate("2 ^ 3 ^ 2"), Fp::from(512));
+        assert_eq!(evaluate("(2 ^ 3) ^ 2"), Fp::from(64));
+        assert_eq!(evaluate("2 * 3 ^ 2 + 1"), Fp::from(19));
+        assert_eq!(evaluate("7 ^ 0"), Fp::one());
+    }
+
+    #[test]
+    fn test_negation() {
+        let field = |input: &str| parse_field::<Fp>(input).unwrap().0.evaluate::<Fp>(&[]);
+        assert_eq!(field("-3"), -Fp::from(3));
+        assert_eq!(field("- -3"), Fp::from(3));
+        assert_eq!(field("5 - -3"), Fp::from(8));
+        assert_eq!(field("2 * -3 + 1"), -Fp::from(5));
+        assert_eq!(field("-(1 + 2)"), -Fp::from(3));
+        assert_eq!(field("-2 ^ 2"), -Fp::from(4));
+
+        // the magnitude of a signed value
+        assert_eq!(evaluate("abs(2 - 5)"), Fp::from(3));
+        assert_eq!(evaluate("abs(0 - 7) + abs(7)"), Fp::from(14));
+        assert_eq!(parse("abs(7)").unwrap().0.constant_value(), Some(7));
+
+        // integers and decimals are unsigned
+        let format = FixedPoint::new(2, Rounding::HalfUp).unwrap();
+        for result in [
+            parse("-3"),
+            parse("5 - -3"),
+            parse_integer::<Fp>("-?a + 1"),
+            parse_fixed("-1.50 + ?a", format),
+        ] {
+            assert!(matches!(result, Err(ParserError::InvalidNegation)));
+        }
+
+        // negation is field negation in field mode and signed in rational
+        // mode
+        let (expression, _) = parse_field::<Fp>("-?a * 2").unwrap();
+        assert_eq!(expression.evaluate(&[Fp::from(3)]), -Fp::from(6));
+        let (expression, _) = parse_rational("-?a / 2").unwrap();
+        let a = Rational::from_token("3").unwrap();
+        assert_eq!(
+            Rational::evaluate(&expression, &[a]).unwrap().to_string(),
+            "-3/2"
+        );
+
+        for input in ["-", "2 -", "-)"] {
+            assert!(parse(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse("2 +"), Err(ParserError::NotEnoughInputs)));
+        assert!(matches!(parse("2 3"), Err(ParserError::TooManyInputs)));
+        assert!(matches!(parse("2 / 3"), Err(ParserError::InvalidOperator)));
+        assert!(matches!(parse("2 + x"), Err(ParserError::InvalidOperator)));
+        assert!(matches!(parse("2a + 3"), Err(ParserError::InvalidOperand)));
+        assert!(matches!(
+            parse("(2 + 3"),
+            Err(ParserError::UnbalancedParentheses)
+        ));
+        assert!(matches!(
+            parse("2 + 3)"),
+            Err(ParserError::UnbalancedParentheses)
+        ));
+        assert!(matches!(parse("2 + ?"), Err(ParserError::InvalidVariable)));
+        assert!(matches!(
+            parse("2 ^ 2 ^ 64"),
+            Err(ParserError::InvalidExponent)
+        ));
+    }
+
+    #[test]
+    fn test_comparisons() {
+        assert_eq!(evaluate("2 < 3"), Fp::one());
+        assert_eq!(evaluate("3 <= 3"), Fp::one());
+        assert_eq!(evaluate("2 > 3"), Fp::zero());
+        assert_eq!(evaluate("2 >= 3"), Fp::zero());
+        assert_eq!(evaluate("3 == 3"), Fp::one());
+        assert_eq!(evaluate("3 != 3"), Fp::zero());
+
+        // comparisons bind looser than arithmetic, equalities loosest
+        assert_eq!(evaluate("1 + 2 * 3 > 6"), Fp::one());
+        assert_eq!(evaluate("1 < 2 == 2 < 1"), Fp::zero());
+
+        assert!(matches!(parse("2 = 3"), Err(ParserError::InvalidOperator)));
+        assert!(matches!(parse("2 =< 3"), Err(ParserError::InvalidOperator)));
+    }
+
+    #[test]
+    fn test_logic() {
+        assert_eq!(evaluate("1 && 0"), Fp::zero());
+        assert_eq!(evaluate("1 || 0"), Fp::one());
+        assert_eq!(evaluate("!0"), Fp::one());
+        assert_eq!(evaluate("!1 || 1 && 0"), Fp::zero());
+        assert_eq!(evaluate("2 < 3 && 3 < 4"), Fp::one());
+
+        // conditionals bind loosest and associate to the right
+        assert_eq!(evaluate("1 ? 2 : 3"), Fp::from(2));
+        assert_eq!(evaluate("0 ? 2 : 1 ? 3 : 4"), Fp::from(3));
+        assert_eq!(evaluate("1 + 1 == 2 ? 5 : 6"), Fp::from(5));
+        assert_eq!(evaluate("(0 ? 2 : 3) * 2"), Fp::from(6));
+
+        // a `?` followed by a name is a variable, otherwise a conditional
+        let (expression, variables) = parse("?x>3?1:?y").unwrap();
+        assert_eq!(variables, vec!["x", "y"]);
+        assert_eq!(
+            expression.evaluate(&[Fp::from(2), Fp::from(7)]),
+            Fp::from(7)
+        );
+
+        assert!(matches!(
+            parse("1 ? 2"),
+            Err(ParserError::InvalidConditional)
+        ));
+        assert!(matches!(parse("1 : 2"), Err(ParserError::TooManyInputs)));
+        assert!(matches!(parse("1 ? : 2"), Err(ParserError::InvalidOperand)));
+    }
+
+    #[test]
+    fn test_bitwise() {
+        assert_eq!(evaluate("12 & 10"), Fp::from(8));
+        assert_eq!(evaluate("12 | 10"), Fp::from(14));
+        assert_eq!(evaluate("12 xor 10"), Fp::from(6));
+        assert_eq!(evaluate("1 << 63"), Fp::from(1 << 63));
+        assert_eq!(evaluate("255 >> 4"), Fp::from(15));
+
+        // bitwise operators bind like in C, shifts tighter than comparisons
+        assert_eq!(evaluate("1 | 2 xor 3 & 6"), Fp::one());
+        assert_eq!(evaluate("1 << 2 + 1 == 8"), Fp::one());
+        assert_eq!(evaluate("2 ^ 3 xor 1"), Fp::from(9));
+
+        // shifts are folded into public constants below 64
+        let (expression, _) = parse("?x << (2 * 3)").unwrap();
+        assert!(matches!(
+            expression,
+            Expression::Binary(Operator::Shl, _, ref rhs) if matches!(**rhs, Expression::Constant(6))
+        ));
+        assert!(expression.any_operator(&|operator| matches!(operator, Operator::Shl)));
+        assert!(!expression.any_operator(&Operator::is_bitwise));
+        assert!(matches!(parse("1 << 64"), Err(ParserError::InvalidShift)));
+        assert!(matches!(parse("1 >> ?n"), Err(ParserError::InvalidShift)));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(evaluate("18446744073709551615 +% 2"), Fp::one());
+        assert_eq!(evaluate("1 -% 2"), Fp::from(u64::MAX));
+        assert_eq!(evaluate("4294967296 *% 4294967297"), Fp::from(1 << 32));
+
+        // wrapping operators bind like their field counterparts
+        assert_eq!(evaluate("1 +% 2 *% 3"), Fp::from(7));
+        assert_eq!(evaluate("0 -% 1 + 1"), Fp::from_u128(1 << 64));
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(evaluate("18446744073709551615 sat+ 2"), Fp::from(u64::MAX));
+        assert_eq!(evaluate("1 sat- 2"), Fp::zero());
+        assert_eq!(evaluate("4294967296 sat* 4294967296"), Fp::from(u64::MAX));
+        assert_eq!(evaluate("2 sat+ 3 sat* 4"), Fp::from(14));
+    }
+
+    #[test]
+    fn test_parse_big() {
+        // 2^128 · 2^128 + 1, far past the field modulus
+        let (expression, variables) =
+            parse_big("340282366920938463463374607431768211456 * ?x + 1").unwrap();
+        assert_eq!(variables, vec!["x"]);
+
+        let x = BigUint::from_decimal("340282366920938463463374607431768211456").unwrap();
+        assert_eq!(expression.evaluate(&[x]).limbs(), &[1, 0, 0, 0, 1]);
+
+        // three limbs times four limbs, plus one limb for the sum
+        assert_eq!(expression.limbs(4), 8);
+
+        assert!(matches!(
+            parse_big("2 - 1"),
+            Err(ParserError::InvalidOperator)
+        ));
+        assert!(matches!(
+            parse_big("(2 + 1"),
+            Err(ParserError::UnbalancedParentheses)
+        ));
+        assert!(matches!(parse_big("2a"), Err(ParserError::InvalidOperand)));
+    }
+
+    #[test]
+    fn test_parse_integer() {
+        // literals past u64 are field element constants below the modulus
+        let two_128 = Fp::from_u128(1 << 64) * Fp::from_u128(1 << 64);
+        let (expression, _) =
+            parse_integer::<Fp>("?a * 340282366920938463463374607431768211456 + 1").unwrap();
+        assert_eq!(
+            expression.evaluate(&[Fp::from(3)]),
+            Fp::from(3) * two_128 + Fp::one()
+        );
+
+        let (expression, _) = parse_integer::<Fp>("18446744073709551616").unwrap();
+        assert!(matches!(expression, Expression::Element(_)));
+        let (expression, _) = parse_integer::<Fp>("18446744073709551615").unwrap();
+        assert_eq!(expression.constant_value(), Some(u64::MAX));
+
+        // the modulus itself is not an integer of the field, and statements
+        // stay over u64
+        let p = "28948022309329048855892746252171976963363056481941560715954676764349967630337";
+        assert!(matches!(
+            parse_integer::<Fp>(p),
+            Err(ParserError::InvalidOperand)
+        ));
+        assert!(parse_integer::<Fq>(p).is_ok());
+        assert!(matches!(
+            parse("18446744073709551616"),
+            Err(ParserError::InvalidOperand)
+        ));
+
+        // literals past u64 only reach field operators and `mod`
+        for input in [
+            "min(3, 18446744073709551616)",
+            "18446744073709551616 & 1",
+            "sqrt(18446744073709551616)",
+            "?a < 18446744073709551616",
+            "?a +% 18446744073709551616",
+            "!18446744073709551616",
+            "18446744073709551616 ? 1 : 2",
+        ] {
+            assert!(matches!(
+                parse_integer::<Fp>(input),
+                Err(ParserError::InvalidOperand)
+            ));
+        }
+        assert!(parse_integer::<Fp>("(?a - 18446744073709551616) ^ 2").is_ok());
+        assert!(parse_integer::<Fp>("18446744073709551616 * ?a mod 7").is_ok());
+    }
+
+    #[test]
+    fn test_parse_field() {
+        let field = |input: &str| parse_field::<Fp>(input).unwrap().0.evaluate::<Fp>(&[]);
+
+        // literals past u64 are single field element constants
+        let max = "28948022309329048855892746252171976963363056481941560715954676764349967630336";
+        assert_eq!(field(max), -Fp::one());
+        assert_eq!(field("0x10000000000000000"), Fp::from_u128(1 << 64));
+        let (expression, _) = parse_field::<Fp>("0x10000000000000000").unwrap();
+        assert!(matches!(expression, Expression::Element(_)));
+
+        // a negative literal is its additive inverse, unless it is raised to
+        // a power
+        assert_eq!(field("-1"), -Fp::one());
+        assert_eq!(field("2 * -0x1f"), -Fp::from(62));
+        assert_eq!(field("-2 ^ 2"), -Fp::from(4));
+        assert_eq!(field(&format!("-{}", max)), Fp::one());
+        let (expression, _) = parse_field::<Fp>("-1").unwrap();
+        assert!(matches!(expression, Expression::Element(_)));
+        assert_eq!(field("0x1f / 0x1f"), Fp::one());
+        assert_eq!(field("1 / 2 * 2"), Fp::one());
+        assert_eq!(field("0 - 1"), -Fp::one());
+
+        // exact quotients fold into constants
+        let (expression, _) = parse_field::<Fp>("6 / 3").unwrap();
+        assert_eq!(expression.constant_value(), Some(2));
+        let (expression, _) = parse_field::<Fp>("1 / 2").unwrap();
+        assert_eq!(expression.constant_value(), None);
+
+        // integer operators are not field operations, division is not an
+        // integer operation
+        for input in ["?a < ?b", "?a & 1", "?a +% 1"] {
+            assert!(matches!(
+                parse_field::<Fp>(input),
+                Err(ParserError::InvalidOperator)
+            ));
+        }
+        assert!(matches!(parse("6 / 3"), Err(ParserError::InvalidOperator)));
+        assert!(matches!(
+            parse_field::<Fp>(&format!("{}1", max)),
+            Err(ParserError::InvalidOperand)
+        ));
+    }
+
+    #[test]
+    fn test_mod() {
+        assert_eq!(evaluate("7 * 8 mod 5"), Fp::from(1));
+        assert_eq!(evaluate("2 - 3 mod 5"), Fp::from(4));
+        assert_eq!(evaluate("3 ^ 200 mod 1000"), Fp::from(1));
+        assert_eq!(
+            parse("3 ^ 40 + 2 mod 7").unwrap().0.constant_value(),
+            Some(6)
+        );
+
+        // `mod` binds looser than arithmetic, tighter than comparisons
+        assert_eq!(evaluate("4 + 5 mod 7 == 2"), Fp::one());
+        assert_eq!(evaluate("10 mod 4 mod 3"), Fp::from(2));
+
+        // the modulus is a constant of at least one, exponents are public
+        for input in ["?a mod ?m", "?a mod 0", "?a ^ ?e mod 7", "mod 7"] {
+            assert!(parse(input).is_err());
+        }
+        assert!(matches!(
+            parse("?a ^ ?e mod 7"),
+            Err(ParserError::InvalidModulus)
+        ));
+        assert!(matches!(
+            parse_field::<Fp>("?a mod 7"),
+            Err(ParserError::InvalidOperator)
+        ));
+    }
+
+    #[test]
+    fn test_function_call() {
+        assert_eq!(evaluate("sqrt(99)"), Fp::from(9));
+        assert_eq!(evaluate("sqrt(100) * 2"), Fp::from(20));
+        assert_eq!(evaluate("sqrt(sqrt(16) + 5)"), Fp::from(3));
+        assert_eq!(parse("sqrt(99)").unwrap().0.constant_value(), Some(9));
+        assert_eq!(evaluate("gcd(12, 18) + lcm(4, 6)"), Fp::from(18));
+        assert_eq!(evaluate("gcd(0, 0)"), Fp::zero());
+        assert_eq!(evaluate("min(5, 2, 8, 2) + max(3, 9)"), Fp::from(11));
+        assert_eq!(
+            parse("max(1, min(7, 4), 3)").unwrap().0.constant_value(),
+            Some(4)
+        );
+
+        // a least common multiple past u64 is rejected, a public one by the
+        // parser and a private one before proving
+        for input in [
+            "lcm(18446744073709551615, 2)",
+            "lcm(18446744073709551615, 18446744073709551614)",
+        ] {
+            assert!(matches!(parse(input), Err(ParserError::InvalidLcm)));
+        }
+        let (expression, _) = parse("1 + lcm(?a, ?b)").unwrap();
+        let values = [Fp::from(u64::MAX), Fp::from(u64::MAX - 1)];
+        assert!(expression.lcm_overflows(&values));
+        assert!(!expression.lcm_overflows(&[Fp::from(u64::MAX), Fp::from(5)]));
+
+        // calls need their parentheses and exactly their arguments
+        for input in [
+            "sqrt 4",
+            "sqrt(4",
+            "sqrt(4 5)",
+            "sqrt()",
+            "gcd(1)",
+            "gcd(1, 2, 3)",
+            "gcd(1 2)",
+            "min(1)",
+            "max(1, 2,)",
+            "1, 2",
+        ] {
+            assert!(parse(input).is_err());
+        }
+        assert!(matches!(parse("sqrt(4"), Err(ParserError::InvalidFunction)));
+        assert!(matches!(
+            parse("cbrt(8)"),
+            Err(ParserError::InvalidOperator)
+        ));
+
+        // functions are over u64
+        assert!(matches!(
+            parse_field::<Fp>("sqrt(4)"),
+            Err(ParserError::InvalidFunction)
+        ));
+        assert!(matches!(
+            parse_rational("sqrt(4)"),
+            Err(ParserError::InvalidFunction)
+        ));
+    }
+
+    #[test]
+    fn test_parse_rational() {
+        let (expression, variables) = parse_rational("(?a - 1) / ?b * 3").unwrap();
+        assert_eq!(variables, vec!["a", "b"]);
+        assert!(expression.is_arithmetic());
+
+        // only arithmetic on i64 literals is rational
+        for input in ["?a ^ 2", "?a < 1", "?a ? 1 : 2", "!?a"] {
+            assert!(matches!(
+                parse_rational(input),
+                Err(ParserError::InvalidOperator)
+            ));
+        }
+        assert!(matches!(
+            parse_rational("9223372036854775808 / 2"),
+            Err(ParserError::InvalidOperand)
+        ));
+    }
+
+    #[test]
+    fn test_private_exponent() {
+        let (expression, variables) = parse("3 ^ ?e").unwrap();
+        assert_eq!(variables, vec!["e"]);
+        assert_eq!(expression.evaluate(&[Fp::from(4)]), Fp::from(81));
+
+        // a public part of a private exponent is not folded
+        let (expression, _) = parse("2 ^ (?e + 1) ^ 2").unwrap();
+        assert_eq!(expression.evaluate(&[Fp::from(2)]), Fp::from(512));
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(
+            parse_threshold("?x >= 1000").unwrap(),
+            ("x".to_string(), 1000)
+        );
+        assert_eq!(
+            parse_threshold("10 * 100 <= ?x").unwrap(),
+            ("x".to_string(), 1000)
+        );
+
+        for input in [
+            "?x > 1000",
+            "?x >= ?y",
+            "?x + 1 >= 1000",
+            "1000 >= ?x",
+            "?x",
+        ] {
+            assert!(matches!(
+                parse_threshold(input),
+                Err(ParserError::InvalidStatement)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_factorization() {
+        assert_eq!(
+            parse_factorization("?p * ?q == 91").unwrap(),
+            ("p".to_string(), "q".to_string(), 91)
+        );
+        assert_eq!(
+            parse_factorization("7 * 13 == ?q * ?p").unwrap(),
+            ("q".to_string(), "p".to_string(), 91)
+        );
+
+        for input in [
+            "?p * ?p == 49",
+            "?p * 7 == 91",
+            "?p + ?q == 91",
+            "?p * ?q == ?n",
+            "?p * ?q >= 91",
+            "?x >= 1000",
+        ] {
+            assert!(matches!(
+                parse_factorization(input),
+                Err(ParserError::InvalidStatement)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_variables() {
+        let (expression, variables) = parse("?x * ?y + ?x * 5").unwrap();
+        assert_eq!(variables, vec!["x", "y"]);
+        assert_eq!(
+            expression.evaluate(&[Fp::from(2), Fp::from(3)]),
+            Fp::from(16)
+        );
+
+        let mut uses = vec![0; variables.len()];
+        expression.count_uses(&mut uses);
+        assert_eq!(uses, vec![2, 1]);
+    }
+}
@@ -0,0 +1,129 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::chips::{
+    add::AddConstantInstructions,
+    arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input, Number},
+    compare::BITS,
+    mul::MulInstructions,
+    range::RangeInstructions,
+};
+
+/// Factorization circuit definition.
+///
+/// Proves knowledge of a factorization `?p * ?q == n` of a public u64 `n`
+/// into two private factors greater than one, without revealing them. The
+/// only public input is `n` (row zero). `p`, `q`, `p - 2` and `q - 2` are
+/// range checked to u64, so both factors are at least two and their product
+/// does not wrap around the field.
+pub struct FactorCircuit<F: FieldExt> {
+    /// Private first factor.
+    pub p: Value<F>,
+    /// Private second factor.
+    pub q: Value<F>,
+}
+
+/// Factorization circuit implementation.
+impl<F: FieldExt> Circuit<F> for FactorCircuit<F> {
+    // reuse the top-level config
+    type Config = ArithmeticConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            p: Value::default(),
+            q: Value::default(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // get advice columns
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        // get instance column
+        let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
+
+        // reuse the ArithmeticChip configuration and return
+        ArithmeticChip::configure(meta, a, b, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // construct the arithmetic chip
+        let arithmetic_chip = ArithmeticChip::<F>::construct(config, ());
+
+        // witness both factors and prove they are greater than one
+        let p = factor(&arithmetic_chip, &mut layouter, self.p)?;
+        let q = factor(&arithmetic_chip, &mut layouter, self.q)?;
+
+        // multiply the factors and constrain the product to the public input
+        let n = arithmetic_chip.mul(&mut layouter, p.into(), q.into())?;
+        arithmetic_chip.expose_public(layouter.namespace(|| "expose n"), n, 0)
+    }
+}
+
+/// Witnesses a factor, range checked to u64, and proves it is greater than
+/// one.
+fn factor<F: FieldExt>(
+    arithmetic_chip: &ArithmeticChip<F>,
+    layouter: &mut impl Layouter<F>,
+    value: Value<F>,
+) -> Result<Number<F>, Error> {
+    // witness the factor directly into its range check, so it is a u64
+    let factor = arithmetic_chip.range_check(layouter, Input::Private(value), BITS)?;
+
+    // the factor minus two is a u64 only if the factor is greater than one
+    let excess = arithmetic_chip.add_constant(layouter, factor.clone().into(), -F::from(2))?;
+    arithmetic_chip.range_check(layouter, excess.into(), BITS)?;
+
+    Ok(factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{arithmetic::Field, dev::MockProver, pasta::Fp};
+
+    #[test]
+    fn test_factor() {
+        let k = 9;
+
+        let max_root = u32::MAX as u64;
+        for (p, q, n, holds) in [
+            (7, 13, 91, true),
+            (2, 2, 4, true),
+            (max_root, max_root, max_root * max_root, true),
+            (7, 13, 92, false),
+            // trivial factorizations are not accepted
+            (1, 91, 91, false),
+            (91, 1, 91, false),
+            (0, 5, 0, false),
+        ] {
+            let circuit = FactorCircuit {
+                p: Value::known(Fp::from(p)),
+                q: Value::known(Fp::from(q)),
+            };
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(n)]]).unwrap();
+            assert_eq!(prover.verify().is_ok(), holds);
+        }
+
+        // a factor outside of u64 is rejected, even if the product wraps
+        // around the field onto `n`
+        let circuit = FactorCircuit {
+            p: Value::known(Fp::from(2)),
+            q: Value::known(Fp::from(91) * Fp::from(2).invert().unwrap()),
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(91)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
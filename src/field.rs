@@ -0,0 +1,184 @@
+use halo2_proofs::{
+    arithmetic::{CurveAffine, FieldExt},
+    pasta::{EpAffine, EqAffine, Fp, Fq},
+};
+
+use crate::{bigint::BigUint, errors::ParserError, zk_calculator::FromToken};
+
+/// Prefix of a hexadecimal field element, e.g. `0x1f`.
+const HEX_PREFIX: &str = "0x";
+
+/// Field a circuit can be proven over.
+/// Real proofs commit on the curve whose scalar field is the circuit's field.
+pub trait ProvingField: FieldExt {
+    /// Curve whose scalar field is this field.
+    type Curve: CurveAffine<ScalarExt = Self>;
+
+    /// Runtime choice of this field.
+    const CHOICE: FieldChoice;
+}
+
+/// The Pallas base field is the scalar field of Vesta.
+impl ProvingField for Fp {
+    type Curve = EqAffine;
+
+    const CHOICE: FieldChoice = FieldChoice::Fp;
+}
+
+/// The Vesta base field is the scalar field of Pallas.
+impl ProvingField for Fq {
+    type Curve = EpAffine;
+
+    const CHOICE: FieldChoice = FieldChoice::Fq;
+}
+
+/// Field selected at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldChoice {
+    /// Pallas base field, proven on Vesta.
+    Fp,
+    /// Vesta base field, proven on Pallas.
+    Fq,
+}
+
+/// Field choice implementation.
+impl FieldChoice {
+    /// Returns the name of the field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldChoice::Fp => "fp",
+            FieldChoice::Fq => "fq",
+        }
+    }
+
+    /// Returns the name of the curve proofs over the field commit on.
+    pub fn curve(&self) -> &'static str {
+        match self {
+            FieldChoice::Fp => "vesta",
+            FieldChoice::Fq => "pallas",
+        }
+    }
+}
+
+/// FromToken implementation for FieldChoice.
+impl FromToken<FieldChoice, ParserError> for FieldChoice {
+    /// Parses a field name, `fp` or `fq`.
+    fn from_token(token: &str) -> Result<FieldChoice, ParserError> {
+        match token {
+            "fp" => Ok(FieldChoice::Fp),
+            "fq" => Ok(FieldChoice::Fq),
+            _ => Err(ParserError::InvalidField),
+        }
+    }
+}
+
+/// Returns the modulus of the field.
+pub fn modulus<F: FieldExt>() -> BigUint {
+    // the modulus is a `0x` prefixed hex string
+    BigUint::from_hex(F::MODULUS.trim_start_matches(HEX_PREFIX)).unwrap()
+}
+
+/// Returns the canonical integer of a field element, in `[0, modulus)`.
+pub fn to_biguint<F: FieldExt>(value: &F) -> BigUint {
+    // the representation is little-endian bytes
+    let limbs: Vec<u64> = value
+        .to_repr()
+        .as_ref()
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0u64, |limb, byte| limb << 8 | *byte as u64)
+        })
+        .collect();
+
+    BigUint::from_limbs(&limbs)
+}
+
+/// Returns the field element congruent to an integer.
+pub fn from_biguint<F: FieldExt>(value: &BigUint) -> F {
+    let base = F::from_u128(1 << 64);
+    value
+        .limbs()
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, limb| acc * base + F::from(*limb))
+}
+
+/// Returns the signed integer of a field element, elements closer to the
+/// modulus than to zero are negative. Returns `None` if it does not fit into
+/// an i128.
+pub fn to_signed<F: FieldExt>(value: &F) -> Option<i128> {
+    let fits = |value: &F| {
+        let lower = value.get_lower_128();
+        (F::from_u128(lower) == *value && lower <= i128::MAX as u128).then_some(lower as i128)
+    };
+
+    fits(value).or_else(|| fits(&-*value).map(|magnitude| -magnitude))
+}
+
+/// Returns the field element congruent to a signed integer.
+pub fn from_signed<F: FieldExt>(value: i128) -> F {
+    match value < 0 {
+        true => -F::from_u128(value.unsigned_abs()),
+        false => F::from_u128(value as u128),
+    }
+}
+
+/// Parses a field literal, decimal or `0x` hex, below the field modulus.
+pub fn parse_literal<F: FieldExt>(token: &str) -> Result<BigUint, ParserError> {
+    let value = match token.strip_prefix(HEX_PREFIX) {
+        Some(hex) => BigUint::from_hex(hex),
+        None => BigUint::from_decimal(token),
+    };
+
+    value
+        .filter(|value| *value < modulus::<F>())
+        .ok_or(ParserError::InvalidOperand)
+}
+
+/// Parses a field element, a leading `-` means the additive inverse.
+pub fn parse_element<F: FieldExt>(input: &str) -> Result<F, ParserError> {
+    match input.strip_prefix('-') {
+        Some(literal) => Ok(-from_biguint::<F>(&parse_literal::<F>(literal)?)),
+        None => Ok(from_biguint(&parse_literal::<F>(input)?)),
+    }
+}
+
+/// Formats a field element canonically, in decimal within `[0, modulus)`.
+pub fn to_canonical<F: FieldExt>(value: &F) -> String {
+    to_biguint(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_element() {
+        let p = "28948022309329048855892746252171976963363056481941560715954676764349967630337";
+        let max = "28948022309329048855892746252171976963363056481941560715954676764349967630336";
+
+        assert_eq!(parse_element::<Fp>("42").unwrap(), Fp::from(42));
+        assert_eq!(parse_element::<Fp>("0x2a").unwrap(), Fp::from(42));
+        assert_eq!(parse_element::<Fp>("-1").unwrap(), -Fp::one());
+        assert_eq!(parse_element::<Fp>("-0").unwrap(), Fp::zero());
+
+        // the largest element round trips, the modulus itself is rejected
+        assert_eq!(modulus::<Fp>().to_string(), p);
+        assert_eq!(to_canonical(&-Fp::one()), max);
+        assert_eq!(parse_element::<Fp>(max).unwrap(), -Fp::one());
+
+        // the Vesta base field is larger, so `p` is an element of it
+        assert!(modulus::<Fq>() > modulus::<Fp>());
+        assert_eq!(to_canonical(&parse_element::<Fq>(p).unwrap()), p);
+
+        for input in [p, "0x", "--1", "1.5"] {
+            assert!(matches!(
+                parse_element::<Fp>(input),
+                Err(ParserError::InvalidOperand)
+            ));
+        }
+    }
+}
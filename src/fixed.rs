@@ -0,0 +1,210 @@
+use std::fmt;
+
+use halo2_proofs::arithmetic::FieldExt;
+
+use crate::{
+    errors::ParserError,
+    field,
+    zk_calculator::{FromToken, Operand},
+};
+
+/// Default number of decimal places, a scale of `10^6`.
+pub const DEFAULT_DECIMALS: u32 = 6;
+
+/// Largest number of decimal places, leaving a u64 room for an integer part.
+pub const MAX_DECIMALS: u32 = 18;
+
+/// Decimal separator of fixed-point literals.
+const DECIMAL_POINT: char = '.';
+
+/// Rounding mode of a fixed-point rescale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds down, dropping the digits past the scale.
+    Floor,
+    /// Rounds up if any digit past the scale is set.
+    Ceil,
+    /// Rounds to the nearest value, halves round up.
+    HalfUp,
+}
+
+/// Rounding implementation.
+impl Rounding {
+    /// Returns the amount added to a value before dividing it by `scale`, so
+    /// the floored quotient is rounded in this mode.
+    pub fn offset(&self, scale: Operand) -> Operand {
+        match self {
+            Rounding::Floor => 0,
+            Rounding::Ceil => scale - 1,
+            Rounding::HalfUp => scale / 2,
+        }
+    }
+
+    /// Returns the name of the rounding mode.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rounding::Floor => "floor",
+            Rounding::Ceil => "ceil",
+            Rounding::HalfUp => "half-up",
+        }
+    }
+}
+
+/// FromToken implementation for Rounding.
+impl FromToken<Rounding, ParserError> for Rounding {
+    /// Parses a rounding mode name, `floor`, `ceil` or `half-up`.
+    fn from_token(token: &str) -> Result<Rounding, ParserError> {
+        match token {
+            "floor" => Ok(Rounding::Floor),
+            "ceil" => Ok(Rounding::Ceil),
+            "half-up" => Ok(Rounding::HalfUp),
+            _ => Err(ParserError::InvalidRounding),
+        }
+    }
+}
+
+/// Fixed-point number format.
+/// A decimal `x` is the u64 integer `x·10^decimals`, so sums need no rescale
+/// and products are divided by the scale once, rounding as configured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedPoint {
+    /// Number of decimal places.
+    pub decimals: u32,
+    /// Rounding mode of products.
+    pub rounding: Rounding,
+}
+
+/// Default fixed-point format, six decimal places rounding half up.
+impl Default for FixedPoint {
+    fn default() -> Self {
+        Self {
+            decimals: DEFAULT_DECIMALS,
+            rounding: Rounding::HalfUp,
+        }
+    }
+}
+
+/// Fixed-point format implementation.
+impl FixedPoint {
+    /// Creates a fixed-point format, at most `MAX_DECIMALS` decimal places.
+    pub fn new(decimals: u32, rounding: Rounding) -> Result<Self, ParserError> {
+        match decimals <= MAX_DECIMALS {
+            true => Ok(Self { decimals, rounding }),
+            false => Err(ParserError::InvalidScale),
+        }
+    }
+
+    /// Returns the scale, `10^decimals`.
+    pub fn scale(&self) -> Operand {
+        10u64.pow(self.decimals)
+    }
+
+    /// Multiplies two fixed-point numbers natively. The product may not fit
+    /// into a u64, which the circuit rejects.
+    pub fn mul(&self, a: Operand, b: Operand) -> u128 {
+        let product = a as u128 * b as u128 + self.rounding.offset(self.scale()) as u128;
+        product / self.scale() as u128
+    }
+
+    /// Parses a decimal literal, e.g. `12.50`, into its scaled integer.
+    /// Literals with more decimal places than the format are rejected
+    /// instead of rounded.
+    pub fn parse_literal(&self, token: &str) -> Result<Operand, ParserError> {
+        let (integer, fraction) = match token.split_once(DECIMAL_POINT) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (token, None),
+        };
+
+        // both parts are non-empty digits, the fraction fits into the scale
+        let digits = |part: &str| !part.is_empty() && part.bytes().all(|c| c.is_ascii_digit());
+        let fraction = match fraction {
+            Some(fraction) if digits(fraction) && fraction.len() <= self.decimals as usize => {
+                fraction
+            }
+            None => "",
+            _ => return Err(ParserError::InvalidOperand),
+        };
+        if !digits(integer) {
+            return Err(ParserError::InvalidOperand);
+        }
+
+        // pad the fraction to the scale
+        let fraction = format!("{:0<width$}", fraction, width = self.decimals as usize);
+
+        // the scaled integer must fit into a u64
+        format!("{}{}", integer, fraction)
+            .parse::<Operand>()
+            .map_err(|_| ParserError::InvalidOperand)
+    }
+
+    /// Formats a field element as a decimal. Field elements closer to the
+    /// modulus than to zero are negative, the result of subtracting a larger
+    /// decimal.
+    pub fn format<F: FieldExt>(&self, value: &F) -> String {
+        let (sign, mut magnitude) = match field::to_biguint(&-*value) < field::to_biguint(value) {
+            true => ("-", field::to_biguint(&-*value)),
+            false => ("", field::to_biguint(value)),
+        };
+
+        // split off the fraction, then print both parts
+        let fraction = magnitude.div_rem_small(self.scale());
+        match self.decimals {
+            0 => format!("{}{}", sign, magnitude),
+            decimals => format!(
+                "{}{}{}{:0width$}",
+                sign,
+                magnitude,
+                DECIMAL_POINT,
+                fraction,
+                width = decimals as usize
+            ),
+        }
+    }
+}
+
+/// Formats the format as `decimals rounding`, e.g. `6 half-up`.
+impl fmt::Display for FixedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.decimals, self.rounding.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::pasta::Fp;
+
+    #[test]
+    fn test_fixed_point() {
+        let cents = FixedPoint::new(2, Rounding::HalfUp).unwrap();
+
+        assert_eq!(cents.parse_literal("12.50").unwrap(), 1250);
+        assert_eq!(cents.parse_literal("12.5").unwrap(), 1250);
+        assert_eq!(cents.parse_literal("7").unwrap(), 700);
+        for input in ["1.234", ".5", "1.", "1.2.3", "1e3", "184467440737095516.16"] {
+            assert!(matches!(
+                cents.parse_literal(input),
+                Err(ParserError::InvalidOperand)
+            ));
+        }
+
+        // 12.50 · 1.08 = 13.5, 0.05 · 0.05 = 0.0025 rounds by the mode
+        assert_eq!(cents.mul(1250, 108), 1350);
+        assert_eq!(cents.mul(5, 5), 0);
+        assert_eq!(cents.mul(7, 7), 0);
+        assert_eq!(FixedPoint::new(2, Rounding::Ceil).unwrap().mul(5, 5), 1);
+        assert_eq!(FixedPoint::new(2, Rounding::Floor).unwrap().mul(9, 9), 0);
+        assert!(cents.mul(u64::MAX, 1000) > u64::MAX as u128);
+
+        assert_eq!(cents.format(&Fp::from(1350)), "13.50");
+        assert_eq!(cents.format(&Fp::from(5)), "0.05");
+        assert_eq!(cents.format(&-Fp::from(1350)), "-13.50");
+        assert_eq!(
+            FixedPoint::new(0, Rounding::Floor)
+                .unwrap()
+                .format(&Fp::from(3)),
+            "3"
+        );
+        assert!(FixedPoint::new(19, Rounding::Floor).is_err());
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::Value,
+    dev::MockProver,
+    pasta::{group::ff::PrimeField, Fp},
+};
+use rand_core::OsRng;
+
+use crate::{
+    chips::hash::hash,
+    errors::{CircuitError, LedgerError, ParserError},
+    field::FieldChoice,
+    ledger_circuit::LedgerCircuit,
+    proof,
+    zk_calculator::{FromToken, Operand, Operator},
+};
+
+/// Default path of the ledger file.
+pub const DEFAULT_LEDGER_PATH: &str = "ledger.txt";
+
+/// Circuit size of an entry, the ledger circuit has two hash regions, so
+/// `2**8` rows are sufficient.
+const LEDGER_K: u32 = 8;
+
+/// Proof that a commitment of the chain evolves into the next one.
+#[derive(Clone, Debug)]
+pub struct Transition {
+    /// Operator of the entry, it selects the shape of the ledger circuit.
+    pub operator: Operator,
+    /// Proof bytes, the previous and the next commitment are its public
+    /// inputs.
+    pub proof: Vec<u8>,
+}
+
+/// Running total ledger.
+///
+/// The ledger file stores the private opening of the latest commitment, the
+/// running total and its salt, followed by the public chain of commitments.
+/// Each session proves that the latest commitment evolves into the next one,
+/// the proof is stored between the two commitments.
+pub struct Ledger {
+    /// Path of the ledger file.
+    path: PathBuf,
+    /// Current running total.
+    total: Fp,
+    /// Salt of the latest commitment.
+    salt: Fp,
+    /// Public chain of commitments, oldest first.
+    commitments: Vec<Fp>,
+    /// Proof of each step of the chain, oldest first.
+    transitions: Vec<Transition>,
+}
+
+/// Ledger implementation.
+impl Ledger {
+    /// Opens the ledger file at `path`.
+    /// Starts a new ledger with a zero total if the file does not exist.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, LedgerError> {
+        let path = path.into();
+
+        // start a new ledger if there is no ledger file yet
+        if !path.exists() {
+            let salt = Fp::random(OsRng);
+            return Ok(Self {
+                path,
+                total: Fp::zero(),
+                salt,
+                commitments: vec![hash(Fp::zero(), salt)],
+                transitions: Vec::new(),
+            });
+        }
+
+        let contents = fs::read_to_string(&path).map_err(LedgerError::Io)?;
+        let mut lines = contents.lines();
+
+        // parse the opening, then the commitment chain
+        let total = parse_entry(lines.next(), "total")?;
+        let salt = parse_entry(lines.next(), "salt")?;
+        let (commitments, transitions) = parse_chain(lines)?;
+
+        // the opening must match the latest commitment
+        match commitments.last() {
+            Some(commitment) if *commitment == hash(total, salt) => (),
+            _ => return Err(LedgerError::CommitmentMismatch),
+        };
+
+        Ok(Self {
+            path,
+            total,
+            salt,
+            commitments,
+            transitions,
+        })
+    }
+
+    /// Runs a single ledger session.
+    /// NOTE: All error code paths should panic here.
+    pub fn run(&mut self) {
+        // get user input.
+        let mut input = String::new();
+        println!("\n\n/- ---------------------------------------------- -/");
+        println!("/- enter ledger entry to apply (format: `+ a`)    -/");
+        // panics if io fails
+        io::stdin().read_line(&mut input).expect("io failed");
+
+        // parse input, panics if parsing fails
+        let (operator, amount) = parse(input).expect("parse failed");
+
+        // run the circuit, panics if circuit fails
+        let commitment = self.apply(operator, amount).expect("circuit failed");
+
+        // persist the new state, panics if io fails
+        self.save().expect("ledger save failed");
+
+        println!(
+            "proof generation successful!\ntotal: {:#?}\ncommitment: {:#?}\nproof appended to {}",
+            self.total,
+            commitment,
+            self.path.display()
+        );
+    }
+
+    /// Applies an entry to the running total, proving the transition from the
+    /// latest commitment to a new one. Returns the new commitment.
+    pub fn apply(&mut self, operator: Operator, amount: Operand) -> Result<Fp, CircuitError> {
+        let amount = Fp::from(amount);
+        let new_salt = Fp::random(OsRng);
+
+        // compute the new total based on the operator
+        let new_total = match operator {
+            Operator::Add => self.total + amount,
+            Operator::Sub => self.total - amount,
+            _ => return Err(CircuitError::UnsupportedOperator),
+        };
+
+        // create the ledger circuit
+        let circuit = LedgerCircuit {
+            total: Value::known(self.total),
+            salt: Value::known(self.salt),
+            amount: Value::known(amount),
+            new_salt: Value::known(new_salt),
+            operator,
+        };
+
+        // public inputs are the previous and the new commitment
+        let commitment = hash(self.total, self.salt);
+        let new_commitment = hash(new_total, new_salt);
+        let public_inputs = vec![commitment, new_commitment];
+
+        // run the mock prover and bubble up any errors
+        let prover = match MockProver::run(LEDGER_K, &circuit, vec![public_inputs.clone()]) {
+            Ok(prover_run) => prover_run,
+            Err(prover_error) => return Err(CircuitError::ProverError(prover_error)),
+        };
+
+        // verify the proof and bubble up any errors
+        match prover.verify() {
+            Ok(_) => (),
+            Err(verifier_error) => return Err(CircuitError::VerifierError(verifier_error)),
+        };
+
+        // prove the transition for real, so the chain can be verified
+        // without the opening
+        let proof = proof::prove(LEDGER_K, circuit, &public_inputs)?;
+
+        // advance the ledger state
+        self.total = new_total;
+        self.salt = new_salt;
+        self.commitments.push(new_commitment);
+        self.transitions.push(Transition { operator, proof });
+
+        Ok(new_commitment)
+    }
+
+    /// Writes the ledger to its file.
+    pub fn save(&self) -> Result<(), LedgerError> {
+        let mut contents = format!(
+            "total {}\nsalt {}\n",
+            to_hex(&self.total),
+            to_hex(&self.salt)
+        );
+
+        // each proof sits between the two commitments it connects
+        contents.push_str(&format!("commitment {}\n", to_hex(&self.commitments[0])));
+        for (commitment, transition) in self.commitments[1..].iter().zip(&self.transitions) {
+            contents.push_str(&format!(
+                "proof {} {}\n",
+                operator_token(transition.operator),
+                proof::to_hex(&transition.proof)
+            ));
+            contents.push_str(&format!("commitment {}\n", to_hex(commitment)));
+        }
+
+        fs::write(&self.path, contents).map_err(LedgerError::Io)
+    }
+}
+
+/// Fails if a field other than `fp` is requested, the ledger commits to
+/// `fp` totals, rather than silently ignoring `--field`.
+pub fn reject_field(field: FieldChoice) -> Result<(), LedgerError> {
+    match field {
+        FieldChoice::Fp => Ok(()),
+        FieldChoice::Fq => Err(LedgerError::UnsupportedField),
+    }
+}
+
+/// Verifies the chain of a ledger file and returns its commitments, oldest
+/// first. Only the public chain is read, so the file may omit the opening.
+pub fn verify(path: impl AsRef<Path>) -> Result<Vec<Fp>, LedgerError> {
+    let contents = fs::read_to_string(path).map_err(LedgerError::Io)?;
+
+    // skip the private opening, if any
+    let lines = contents
+        .lines()
+        .filter(|line| !line.starts_with("total ") && !line.starts_with("salt "));
+    let (commitments, transitions) = parse_chain(lines)?;
+
+    // each proof connects a commitment to the next one
+    for (pair, transition) in commitments.windows(2).zip(&transitions) {
+        // the verifier only needs the shape of the circuit
+        let circuit = LedgerCircuit {
+            total: Value::unknown(),
+            salt: Value::unknown(),
+            amount: Value::unknown(),
+            new_salt: Value::unknown(),
+            operator: transition.operator,
+        };
+
+        proof::verify(LEDGER_K, &circuit, pair, &transition.proof).map_err(LedgerError::Circuit)?;
+    }
+
+    Ok(commitments)
+}
+
+/// Parses the public chain, the first commitment followed by a proof and
+/// the next commitment for every entry.
+fn parse_chain<'a>(
+    mut lines: impl Iterator<Item = &'a str>,
+) -> Result<(Vec<Fp>, Vec<Transition>), LedgerError> {
+    let mut commitments = vec![parse_entry(lines.next(), "commitment")?];
+    let mut transitions = Vec::new();
+
+    while let Some(line) = lines.next() {
+        transitions.push(parse_transition(line)?);
+        commitments.push(parse_entry(lines.next(), "commitment")?);
+    }
+
+    Ok((commitments, transitions))
+}
+
+/// Parses a `proof operator hex` ledger line into a transition.
+fn parse_transition(line: &str) -> Result<Transition, LedgerError> {
+    let (operator, proof) = line
+        .strip_prefix("proof ")
+        .and_then(|line| line.split_once(' '))
+        .ok_or(LedgerError::MalformedLedger)?;
+
+    // a ledger only adds or subtracts amounts
+    let operator = match Operator::from_token(operator) {
+        Ok(operator @ (Operator::Add | Operator::Sub)) => operator,
+        _ => return Err(LedgerError::MalformedLedger),
+    };
+    let proof = proof::from_hex(proof).map_err(|_| LedgerError::MalformedLedger)?;
+
+    Ok(Transition { operator, proof })
+}
+
+/// Returns the token of a ledger operator, `+` or `-`.
+fn operator_token(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Sub => "-",
+        _ => "+",
+    }
+}
+
+/// Parses a ledger entry into an Operator and an amount.
+/// Only `+` and `-` entries are valid.
+fn parse(input: String) -> Result<(Operator, Operand), ParserError> {
+    // split input by whitespace
+    let mut tokens = input.split_whitespace();
+
+    // parse into operator or bubble up error
+    let operator = match tokens.next() {
+        Some(op) => Operator::from_token(op),
+        None => Err(ParserError::NotEnoughInputs),
+    }?;
+
+    if !matches!(operator, Operator::Add | Operator::Sub) {
+        return Err(ParserError::InvalidOperator);
+    }
+
+    // parse into amount or bubble up error
+    let amount = match tokens.next() {
+        Some(amount) => Operand::from_token(amount),
+        None => Err(ParserError::NotEnoughInputs),
+    }?;
+
+    if tokens.next().is_some() {
+        return Err(ParserError::TooManyInputs);
+    }
+
+    Ok((operator, amount))
+}
+
+/// Parses a `label 0x..` ledger line into a field element.
+fn parse_entry(line: Option<&str>, label: &str) -> Result<Fp, LedgerError> {
+    let value = line
+        .and_then(|line| line.strip_prefix(label))
+        .and_then(|line| line.trim().strip_prefix("0x"))
+        .ok_or(LedgerError::MalformedLedger)?;
+
+    from_hex(value).ok_or(LedgerError::MalformedLedger)
+}
+
+/// Encodes a field element as big-endian hex, matching its `Debug` output.
+fn to_hex(value: &Fp) -> String {
+    let hex = value
+        .to_repr()
+        .iter()
+        .rev()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    format!("0x{}", hex)
+}
+
+/// Decodes big-endian hex into a canonical field element.
+fn from_hex(hex: &str) -> Option<Fp> {
+    if hex.len() != 64 {
+        return None;
+    }
+
+    let mut repr = [0u8; 32];
+    for (i, byte) in repr.iter_mut().rev().enumerate() {
+        *byte = u8::from_str_radix(hex.get(2 * i..2 * i + 2)?, 16).ok()?;
+    }
+
+    Option::from(Fp::from_repr(repr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::RngCore;
+
+    #[test]
+    fn test_ledger_file_roundtrip() {
+        // a unique file, so concurrent test runs do not share it
+        let path = std::env::temp_dir().join(format!(
+            "zk-calculator-ledger-{:016x}.txt",
+            OsRng.next_u64()
+        ));
+
+        let mut ledger = Ledger::open(&path).unwrap();
+        ledger.apply(Operator::Add, 100).unwrap();
+        ledger.apply(Operator::Sub, 30).unwrap();
+        ledger.save().unwrap();
+
+        let reopened = Ledger::open(&path).unwrap();
+        assert_eq!(reopened.total, Fp::from(70));
+        assert_eq!(reopened.commitments.len(), 3);
+        assert_eq!(reopened.commitments, ledger.commitments);
+
+        // the chain verifies without the opening
+        let contents = fs::read_to_string(&path).unwrap();
+        let public: String = contents
+            .lines()
+            .skip(2)
+            .map(|line| format!("{}\n", line))
+            .collect();
+        fs::write(&path, &public).unwrap();
+        assert_eq!(verify(&path).unwrap(), ledger.commitments);
+
+        // a proof does not verify for the opposite entry
+        fs::write(&path, public.replacen("proof + ", "proof - ", 1)).unwrap();
+        assert!(matches!(verify(&path), Err(LedgerError::Circuit(_))));
+
+        // nor for a different commitment
+        let forged = public.replacen(
+            &to_hex(&ledger.commitments[1]),
+            &to_hex(&hash(Fp::from(1000), Fp::one())),
+            1,
+        );
+        fs::write(&path, forged).unwrap();
+        assert!(matches!(verify(&path), Err(LedgerError::Circuit(_))));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,195 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    chips::{
+        add::AddInstructions,
+        arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input},
+        hash::{HashChip, HashConfig, HashInstructions},
+        sub::SubInstructions,
+    },
+    zk_calculator::Operator,
+};
+
+/// Ledger circuit configuration.
+/// Only the ledger circuit configures the hash chip, so other circuits do not
+/// carry its degree 6 round gate and round constant column.
+#[derive(Clone, Debug)]
+pub struct LedgerConfig {
+    /// Top-level arithmetic configuration.
+    arithmetic_config: ArithmeticConfig,
+    /// Hash chip configuration, on the same advice columns.
+    hash_config: HashConfig,
+}
+
+/// Ledger circuit definition.
+///
+/// Proves that the state committed to by the previous commitment, updated by
+/// a private amount, is the state committed to by the new commitment.
+/// Public inputs are the previous commitment (row zero) and the new
+/// commitment (row one).
+pub struct LedgerCircuit<F: FieldExt> {
+    /// Previous running total.
+    pub total: Value<F>,
+    /// Previous commitment salt.
+    pub salt: Value<F>,
+    /// Amount applied to the running total.
+    pub amount: Value<F>,
+    /// New commitment salt.
+    pub new_salt: Value<F>,
+    /// Operator applied to the running total and amount.
+    pub operator: Operator,
+}
+
+/// Ledger circuit implementation.
+impl<F: FieldExt> Circuit<F> for LedgerCircuit<F> {
+    // the top-level config next to the hash chip
+    type Config = LedgerConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            total: Value::default(),
+            salt: Value::default(),
+            amount: Value::default(),
+            new_salt: Value::default(),
+            operator: self.operator,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // get advice columns
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        // get instance column
+        let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
+
+        // reuse the ArithmeticChip configuration, add the hash chip and
+        // return
+        LedgerConfig {
+            arithmetic_config: ArithmeticChip::configure(meta, a, b, instance, constant),
+            hash_config: HashChip::configure(meta, a, b),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // construct the arithmetic and hash chips
+        let arithmetic_chip = ArithmeticChip::<F>::construct(config.arithmetic_config, ());
+        let hash_chip = HashChip::<F>::construct(config.hash_config, ());
+
+        // load the total into the circuit, it is used by both the commitment
+        // opening and the update, the remaining values are used once and are
+        // witnessed directly into their instruction's region
+        let total: Input<F> = arithmetic_chip
+            .load_private(layouter.namespace(|| "load total"), self.total)?
+            .into();
+        let salt = Input::Private(self.salt);
+        let amount = Input::Private(self.amount);
+        let new_salt = Input::Private(self.new_salt);
+
+        // open the previous commitment
+        let commitment = hash_chip.hash(&mut layouter, total.clone(), salt)?;
+        arithmetic_chip.expose_public(layouter.namespace(|| "expose commitment"), commitment, 0)?;
+
+        // apply the amount to the running total
+        let new_total = match &self.operator {
+            Operator::Add => arithmetic_chip.add(&mut layouter, total, amount),
+            Operator::Sub => arithmetic_chip.sub(&mut layouter, total, amount),
+            // a ledger only adds or subtracts amounts
+            _ => Err(Error::Synthesis),
+        }?;
+
+        // commit to the new running total
+        let new_commitment = hash_chip.hash(&mut layouter, new_total.into(), new_salt)?;
+        arithmetic_chip.expose_public(
+            layouter.namespace(|| "expose new commitment"),
+            new_commitment,
+            1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chips::hash::hash;
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    #[test]
+    fn test_ledger() {
+        let k = 8;
+
+        let total = Fp::from(100);
+        let salt = Fp::from(7);
+        let amount = Fp::from(25);
+        let new_salt = Fp::from(11);
+
+        let circuit = LedgerCircuit {
+            total: Value::known(total),
+            salt: Value::known(salt),
+            amount: Value::known(amount),
+            new_salt: Value::known(new_salt),
+            operator: Operator::Sub,
+        };
+
+        let mut public_inputs = vec![hash(total, salt), hash(total - amount, new_salt)];
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        public_inputs[1] = hash(total + amount, new_salt);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_ledger_chain() {
+        let k = 8;
+
+        let salts = [Fp::from(3), Fp::from(5), Fp::from(8)];
+        let amounts = [Fp::from(40), Fp::from(2)];
+
+        let mut total = Fp::zero();
+
+        for (i, amount) in amounts.iter().enumerate() {
+            let circuit = LedgerCircuit {
+                total: Value::known(total),
+                salt: Value::known(salts[i]),
+                amount: Value::known(*amount),
+                new_salt: Value::known(salts[i + 1]),
+                operator: Operator::Add,
+            };
+
+            let public_inputs = vec![hash(total, salts[i]), hash(total + amount, salts[i + 1])];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            total += amount;
+        }
+
+        // a wrong opening of the previous commitment must not verify
+        let circuit = LedgerCircuit {
+            total: Value::known(total + Fp::one()),
+            salt: Value::known(salts[2]),
+            amount: Value::known(Fp::one()),
+            new_salt: Value::known(salts[0]),
+            operator: Operator::Add,
+        };
+
+        let public_inputs = vec![hash(total, salts[2]), hash(total + Fp::from(2), salts[0])];
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
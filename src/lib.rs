@@ -0,0 +1,20 @@
+pub mod bigint;
+pub mod bigint_circuit;
+pub mod calculator_circuit;
+pub mod chips;
+pub mod errors;
+pub mod expression;
+pub mod factor_circuit;
+pub mod field;
+pub mod fixed;
+pub mod ledger;
+pub mod ledger_circuit;
+pub mod proof;
+pub mod rational;
+pub mod rational_circuit;
+pub mod threshold_circuit;
+pub mod zk_calculator;
+
+// the statement proofs are the library API, callable as e.g.
+// `zk_calculator::prove_threshold`
+pub use zk_calculator::{prove_factorization, prove_threshold};
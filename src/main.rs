@@ -1,10 +1,118 @@
-mod calculator_circuit;
-mod chips;
-mod errors;
-mod zk_calculator;
+use std::{env, path::PathBuf};
 
-use zk_calculator::ZkCalculator;
+use halo2_proofs::pasta::{Fp, Fq};
+
+use zk_calculator::{
+    field::{FieldChoice, ProvingField},
+    fixed::{FixedPoint, Rounding, DEFAULT_DECIMALS},
+    ledger::{self, Ledger, DEFAULT_LEDGER_PATH},
+    proof::{self, ProofArtifact},
+    zk_calculator::{FromToken, ZkCalculator},
+};
 
 fn main() {
-    ZkCalculator::new().run();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    // `--field fp|fq` selects the field, the Pallas base field by default
+    let field = take_option(&mut args, "--field")
+        .map(|name| FieldChoice::from_token(&name))
+        .transpose()
+        .expect("parse failed")
+        .unwrap_or(FieldChoice::Fp);
+
+    // `--proof path` writes a real proof artifact of the calculation
+    let proof_path = take_option(&mut args, "--proof").map(PathBuf::from);
+
+    // `--decimals n` and `--rounding floor|ceil|half-up` set the fixed-point
+    // format of decimal mode
+    let decimals = take_option(&mut args, "--decimals")
+        .map(|decimals| decimals.parse().expect("parse failed"))
+        .unwrap_or(DEFAULT_DECIMALS);
+    let rounding = take_option(&mut args, "--rounding")
+        .map(|name| Rounding::from_token(&name))
+        .transpose()
+        .expect("parse failed")
+        .unwrap_or(Rounding::HalfUp);
+    let format = FixedPoint::new(decimals, rounding).expect("parse failed");
+
+    match args.first().map(String::as_str) {
+        // `zk-calculator ledger [path]` applies one entry to a running total
+        Some("ledger") => {
+            // the ledger writes no proof artifact, panics if one is requested
+            proof::reject_artifact(proof_path.as_deref()).expect("proof failed");
+            // the ledger is over `fp`, panics if another field is requested
+            ledger::reject_field(field).expect("ledger failed");
+
+            let path = args.get(1).map_or(DEFAULT_LEDGER_PATH, String::as_str);
+            Ledger::open(path).expect("ledger open failed").run();
+        }
+        // `zk-calculator verify-ledger [path]` verifies the proof of every
+        // entry of a ledger chain
+        Some("verify-ledger") => {
+            // verifying writes no proof artifact, panics if one is requested
+            proof::reject_artifact(proof_path.as_deref()).expect("proof failed");
+            // the ledger is over `fp`, panics if another field is requested
+            ledger::reject_field(field).expect("ledger failed");
+
+            let path = args.get(1).map_or(DEFAULT_LEDGER_PATH, String::as_str);
+            let commitments = ledger::verify(path).expect("ledger verification failed");
+
+            println!(
+                "ledger verification successful!\nentries: {}\ncommitment: {:#?}",
+                commitments.len() - 1,
+                commitments[commitments.len() - 1]
+            );
+        }
+        // `zk-calculator verify path` verifies a proof artifact over the
+        // field it records
+        Some("verify") => {
+            // verifying writes no proof artifact, panics if one is requested
+            proof::reject_artifact(proof_path.as_deref()).expect("proof failed");
+
+            let path = args.get(1).expect("missing proof artifact path");
+            let artifact = ProofArtifact::read(path).expect("proof read failed");
+            artifact.verify().expect("verification failed");
+
+            println!(
+                "proof verification successful!\nfield: {} ({})\nstatement: {}\nresult: {}",
+                artifact.field.name(),
+                artifact.field.curve(),
+                artifact.statement,
+                artifact.public_inputs.join(", ")
+            );
+        }
+        command => match field {
+            FieldChoice::Fp => run::<Fp>(command, proof_path, format),
+            FieldChoice::Fq => run::<Fq>(command, proof_path, format),
+        },
+    }
+}
+
+/// Runs a calculator command over the field `F`.
+fn run<F: ProvingField>(command: Option<&str>, proof_path: Option<PathBuf>, format: FixedPoint) {
+    let mut calculator = ZkCalculator::<F>::new(proof_path);
+
+    match command {
+        // `zk-calculator bigint` computes over arbitrary-precision integers
+        Some("bigint") => calculator.run_bigint(),
+        // `zk-calculator field` computes over raw field elements
+        Some("field") => calculator.run_field(),
+        // `zk-calculator rational` computes over exact fractions
+        Some("rational") => calculator.run_rational(),
+        // `zk-calculator decimal` computes over fixed-point decimals
+        Some("decimal") => calculator.run_decimal(format),
+        // `zk-calculator` computes over u64 integers
+        None => calculator.run(),
+        // a mistyped command is not silently run as an integer calculation
+        Some(command) => panic!("unknown command `{}`", command),
+    }
+}
+
+/// Removes a `name value` option from the arguments and returns its value.
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == name)?;
+    args.remove(index);
+
+    // an option without a value is ignored
+    (index < args.len()).then(|| args.remove(index))
 }
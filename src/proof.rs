@@ -0,0 +1,588 @@
+use std::{fmt, fs, path::Path};
+
+use halo2_proofs::{
+    circuit::Value,
+    pasta::{Fp, Fq},
+    plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, SingleVerifier},
+    poly::commitment::Params,
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+};
+use rand_core::OsRng;
+
+use crate::{
+    bigint_circuit::{BigIntCircuit, DEFAULT_VARIABLE_LIMBS},
+    calculator_circuit::{CalculatorCircuit, DEFAULT_EXPONENT_BITS},
+    errors::{CircuitError, ProofError},
+    expression,
+    factor_circuit::FactorCircuit,
+    field::{self, FieldChoice, ProvingField},
+    rational_circuit::RationalCircuit,
+    threshold_circuit::ThresholdCircuit,
+    zk_calculator::{FromToken, Mode, PROVE_PREFIX},
+};
+
+/// Creates a real proof of a circuit with `2**k` rows. Keys are derived from
+/// the circuit shape and the transparent IPA parameters, so the verifier can
+/// derive them again without a trusted setup.
+pub fn prove<F: ProvingField, C: Circuit<F>>(
+    k: u32,
+    circuit: C,
+    public_inputs: &[F],
+) -> Result<Vec<u8>, CircuitError> {
+    // generate parameters and keys on the field's curve
+    let params = Params::<F::Curve>::new(k);
+    let vk = keygen_vk(&params, &circuit.without_witnesses()).map_err(CircuitError::ProverError)?;
+    let pk =
+        keygen_pk(&params, vk, &circuit.without_witnesses()).map_err(CircuitError::ProverError)?;
+
+    // create the proof into a transcript and return its bytes
+    let mut transcript = Blake2bWrite::<_, F::Curve, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[&[public_inputs]],
+        OsRng,
+        &mut transcript,
+    )
+    .map_err(CircuitError::ProverError)?;
+
+    Ok(transcript.finalize())
+}
+
+/// Verifies a real proof of a circuit with `2**k` rows. Only the shape of
+/// the circuit is used, its witnesses may be unknown.
+pub fn verify<F: ProvingField, C: Circuit<F>>(
+    k: u32,
+    circuit: &C,
+    public_inputs: &[F],
+    proof: &[u8],
+) -> Result<(), CircuitError> {
+    // derive the parameters and the verifying key again
+    let params = Params::<F::Curve>::new(k);
+    let vk = keygen_vk(&params, circuit).map_err(CircuitError::ProverError)?;
+
+    // verify the proof from its transcript
+    let mut transcript = Blake2bRead::<_, F::Curve, Challenge255<_>>::init(proof);
+    verify_proof(
+        &params,
+        &vk,
+        SingleVerifier::new(&params),
+        &[&[public_inputs]],
+        &mut transcript,
+    )
+    .map_err(CircuitError::InvalidProof)
+}
+
+/// Fails if a proof artifact is requested, for commands that do not write
+/// one rather than silently ignoring `--proof`.
+pub fn reject_artifact(path: Option<&Path>) -> Result<(), ProofError> {
+    match path {
+        Some(_) => Err(ProofError::UnsupportedCommand),
+        None => Ok(()),
+    }
+}
+
+/// Mode of a proof artifact, selects the circuit the verifier rebuilds from
+/// the statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofMode {
+    /// Integer, field or decimal calculation, or an integer statement.
+    Calculator(Mode),
+    /// Big integer calculation, the public inputs are the result limbs.
+    BigInt,
+    /// Rational calculation, the public inputs are the reduced numerator
+    /// and denominator.
+    Rational,
+}
+
+/// ProofMode implementation.
+impl ProofMode {
+    /// Returns the mode of a name, as formatted by `Display`, e.g. `bigint`
+    /// or `decimal 6 half-up`.
+    pub fn from_name(name: &str) -> Option<ProofMode> {
+        match name {
+            "bigint" => Some(ProofMode::BigInt),
+            "rational" => Some(ProofMode::Rational),
+            name => Mode::from_name(name).map(ProofMode::Calculator),
+        }
+    }
+}
+
+/// Converts a calculator mode into the mode of its artifacts.
+impl From<Mode> for ProofMode {
+    fn from(mode: Mode) -> Self {
+        ProofMode::Calculator(mode)
+    }
+}
+
+/// Formats the mode by name, calculator modes as `Mode` formats them.
+impl fmt::Display for ProofMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofMode::Calculator(mode) => write!(f, "{}", mode),
+            ProofMode::BigInt => write!(f, "bigint"),
+            ProofMode::Rational => write!(f, "rational"),
+        }
+    }
+}
+
+/// Proof artifact of a calculation.
+///
+/// Records the field and curve the proof is over, next to everything the
+/// verifier needs to rebuild the circuit shape, one `label value` per line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofArtifact {
+    /// Field the circuit is over, selects the curve of the proof.
+    pub field: FieldChoice,
+    /// Circuit size, `2**k` rows.
+    pub k: u32,
+    /// Mode the statement is parsed in.
+    pub mode: ProofMode,
+    /// Expression the proof computes, or the `prove` statement it proves.
+    pub statement: String,
+    /// Public inputs, canonical decimal field elements.
+    pub public_inputs: Vec<String>,
+    /// Proof bytes.
+    pub proof: Vec<u8>,
+}
+
+/// Proof artifact implementation.
+impl ProofArtifact {
+    /// Proves a calculation or a statement and returns its artifact.
+    pub fn prove<F: ProvingField, C: Circuit<F>>(
+        k: u32,
+        mode: ProofMode,
+        statement: &str,
+        circuit: C,
+        public_inputs: &[F],
+    ) -> Result<Self, CircuitError> {
+        let proof = prove(k, circuit, public_inputs)?;
+
+        Ok(Self {
+            field: F::CHOICE,
+            k,
+            mode,
+            statement: statement.trim().to_string(),
+            public_inputs: public_inputs.iter().map(field::to_canonical).collect(),
+            proof,
+        })
+    }
+
+    /// Verifies the proof over the field the artifact records.
+    pub fn verify(&self) -> Result<(), ProofError> {
+        match self.field {
+            FieldChoice::Fp => self.verify_over::<Fp>(),
+            FieldChoice::Fq => self.verify_over::<Fq>(),
+        }
+    }
+
+    /// Verifies the proof over a field, rebuilding the circuit shape from the
+    /// statement.
+    fn verify_over<F: ProvingField>(&self) -> Result<(), ProofError> {
+        // the public inputs were written by a prover, so a failure to parse
+        // them means the artifact is malformed
+        let public_inputs = self
+            .public_inputs
+            .iter()
+            .map(|input| field::parse_element::<F>(input))
+            .collect::<Result<Vec<F>, _>>()
+            .map_err(|_| ProofError::MalformedArtifact)?;
+
+        match (self.mode, self.statement.strip_prefix(PROVE_PREFIX)) {
+            (ProofMode::Calculator(_), Some(statement)) => {
+                self.verify_statement(statement, &public_inputs)
+            }
+            (ProofMode::Calculator(mode), None) => self.verify_calculation(mode, &public_inputs),
+            (ProofMode::BigInt, _) => self.verify_bigint(&public_inputs),
+            (ProofMode::Rational, _) => self.verify_rational(&public_inputs),
+        }
+    }
+
+    /// Verifies the proof of a `prove` statement, its public inputs must be
+    /// the constants of the statement.
+    fn verify_statement<F: ProvingField>(
+        &self,
+        statement: &str,
+        public_inputs: &[F],
+    ) -> Result<(), ProofError> {
+        // statements are only parsed in integer mode
+        if self.mode != ProofMode::Calculator(Mode::Integer) {
+            return Err(ProofError::MalformedArtifact);
+        }
+
+        // a factorization statement, `n` is the public input
+        if let Ok((_, _, n)) = expression::parse_factorization(statement) {
+            if public_inputs != [F::from(n)] {
+                return Err(ProofError::MalformedArtifact);
+            }
+
+            // the verifier only needs the shape of the circuit
+            let circuit = FactorCircuit {
+                p: Value::unknown(),
+                q: Value::unknown(),
+            };
+
+            return verify(self.k, &circuit, public_inputs, &self.proof)
+                .map_err(ProofError::Circuit);
+        }
+
+        // otherwise a threshold statement, the threshold is the public input
+        let (_, threshold) =
+            expression::parse_threshold(statement).map_err(|_| ProofError::MalformedArtifact)?;
+        if public_inputs != [F::from(threshold)] {
+            return Err(ProofError::MalformedArtifact);
+        }
+
+        // the verifier only needs the shape of the circuit
+        let circuit = ThresholdCircuit {
+            value: Value::unknown(),
+            threshold: Value::unknown(),
+        };
+
+        verify(self.k, &circuit, public_inputs, &self.proof).map_err(ProofError::Circuit)
+    }
+
+    /// Verifies the proof of a calculation, the public inputs are its result.
+    fn verify_calculation<F: ProvingField>(
+        &self,
+        mode: Mode,
+        public_inputs: &[F],
+    ) -> Result<(), ProofError> {
+        // the statement was written by a prover, so a failure to parse it
+        // means the artifact is malformed
+        let (expression, variables) = mode
+            .parse::<F>(&self.statement)
+            .map_err(|_| ProofError::MalformedArtifact)?;
+
+        // the verifier only needs the shape of the circuit
+        let circuit = CalculatorCircuit {
+            expression,
+            variables: vec![Value::unknown(); variables.len()],
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        };
+
+        verify(self.k, &circuit, public_inputs, &self.proof).map_err(ProofError::Circuit)
+    }
+
+    /// Verifies the proof of a big integer calculation, the public inputs are
+    /// the limbs of its result.
+    fn verify_bigint<F: ProvingField>(&self, public_inputs: &[F]) -> Result<(), ProofError> {
+        let (expression, variables) =
+            expression::parse_big(&self.statement).map_err(|_| ProofError::MalformedArtifact)?;
+
+        // the verifier only needs the shape of the circuit, variables have
+        // the limbs the calculator reads them into
+        let circuit = BigIntCircuit {
+            expression,
+            variables: vec![Value::unknown(); variables.len()],
+            variable_limbs: DEFAULT_VARIABLE_LIMBS,
+        };
+
+        verify(self.k, &circuit, public_inputs, &self.proof).map_err(ProofError::Circuit)
+    }
+
+    /// Verifies the proof of a rational calculation, the public inputs are
+    /// the reduced numerator and denominator of its result.
+    fn verify_rational<F: ProvingField>(&self, public_inputs: &[F]) -> Result<(), ProofError> {
+        let (expression, variables) = expression::parse_rational(&self.statement)
+            .map_err(|_| ProofError::MalformedArtifact)?;
+
+        // the verifier only needs the shape of the circuit
+        let circuit = RationalCircuit {
+            expression,
+            variables: vec![Value::unknown(); variables.len()],
+        };
+
+        verify(self.k, &circuit, public_inputs, &self.proof).map_err(ProofError::Circuit)
+    }
+
+    /// Parses an artifact from its text format.
+    pub fn parse(contents: &str) -> Result<Self, ProofError> {
+        let mut lines = contents.lines();
+
+        // parse the header, the curve must be the one of the field
+        let field = FieldChoice::from_token(entry(lines.next(), "field")?)
+            .map_err(|_| ProofError::MalformedArtifact)?;
+        if entry(lines.next(), "curve")? != field.curve() {
+            return Err(ProofError::MalformedArtifact);
+        }
+        let k = entry(lines.next(), "k")?
+            .parse()
+            .map_err(|_| ProofError::MalformedArtifact)?;
+        let mode = ProofMode::from_name(entry(lines.next(), "mode")?)
+            .ok_or(ProofError::MalformedArtifact)?;
+        let statement = entry(lines.next(), "statement")?.to_string();
+
+        // parse the public inputs, then the proof
+        let mut public_inputs = Vec::new();
+        let proof = loop {
+            let line = lines.next().ok_or(ProofError::MalformedArtifact)?;
+            match line.split_once(' ') {
+                Some(("instance", input)) => public_inputs.push(input.to_string()),
+                Some(("proof", proof)) => break from_hex(proof)?,
+                _ => return Err(ProofError::MalformedArtifact),
+            }
+        };
+
+        Ok(Self {
+            field,
+            k,
+            mode,
+            statement,
+            public_inputs,
+            proof,
+        })
+    }
+
+    /// Reads an artifact from a file.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, ProofError> {
+        Self::parse(&fs::read_to_string(path).map_err(ProofError::Io)?)
+    }
+
+    /// Writes the artifact to a file.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), ProofError> {
+        fs::write(path, self.to_string()).map_err(ProofError::Io)
+    }
+}
+
+/// Formats the artifact in its text format.
+impl fmt::Display for ProofArtifact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "field {}", self.field.name())?;
+        writeln!(f, "curve {}", self.field.curve())?;
+        writeln!(f, "k {}", self.k)?;
+        writeln!(f, "mode {}", self.mode)?;
+        writeln!(f, "statement {}", self.statement)?;
+        for input in &self.public_inputs {
+            writeln!(f, "instance {}", input)?;
+        }
+        writeln!(f, "proof {}", to_hex(&self.proof))
+    }
+}
+
+/// Returns the value of a `label value` artifact line.
+fn entry<'a>(line: Option<&'a str>, label: &str) -> Result<&'a str, ProofError> {
+    match line.and_then(|line| line.split_once(' ')) {
+        Some((found, value)) if found == label => Ok(value),
+        _ => Err(ProofError::MalformedArtifact),
+    }
+}
+
+/// Encodes proof bytes as hex.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decodes hex encoded proof bytes.
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, ProofError> {
+    // an odd trailing digit has no byte to get
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or(ProofError::MalformedArtifact)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expression::parse,
+        zk_calculator::{prove_factorization, prove_threshold},
+    };
+
+    fn artifact<F: ProvingField>(input: &str, values: &[u64]) -> ProofArtifact {
+        let (expression, _) = parse(input).unwrap();
+        let values: Vec<F> = values.iter().copied().map(F::from).collect();
+        let c = expression.evaluate(&values);
+
+        let circuit = CalculatorCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
+            exponent_bits: DEFAULT_EXPONENT_BITS,
+        };
+
+        ProofArtifact::prove(5, Mode::Integer.into(), input, circuit, &[c]).unwrap()
+    }
+
+    #[test]
+    fn test_artifact() {
+        for artifact in [
+            artifact::<Fp>("?a * ?b + 3", &[6, 7]),
+            artifact::<Fq>("?a * ?b + 3", &[6, 7]),
+        ] {
+            assert_eq!(artifact.public_inputs, vec!["45"]);
+
+            // the artifact round trips through its text format
+            let parsed = ProofArtifact::parse(&artifact.to_string()).unwrap();
+            assert_eq!(parsed, artifact);
+            assert!(parsed.verify().is_ok());
+
+            // a different result is not accepted
+            let wrong = ProofArtifact {
+                public_inputs: vec!["46".to_string()],
+                ..artifact.clone()
+            };
+            assert!(matches!(
+                wrong.verify(),
+                Err(ProofError::Circuit(CircuitError::InvalidProof(_)))
+            ));
+        }
+
+        // the curve is recorded next to the field, and both must agree
+        let fq = artifact::<Fq>("?a + 1", &[1]);
+        assert!(fq.to_string().starts_with("field fq\ncurve pallas\n"));
+
+        let swapped = ProofArtifact {
+            field: FieldChoice::Fp,
+            ..fq.clone()
+        };
+        assert!(swapped.verify().is_err());
+        assert!(matches!(
+            ProofArtifact::parse(&fq.to_string().replace("pallas", "vesta")),
+            Err(ProofError::MalformedArtifact)
+        ));
+    }
+
+    #[test]
+    fn test_bigint_and_rational_artifacts() {
+        use crate::{
+            bigint::BigUint,
+            bigint_circuit,
+            expression::{parse_big, parse_rational},
+            rational::Rational,
+            rational_circuit,
+            zk_calculator::mock_prove,
+        };
+
+        // a product past the field modulus, public as limbs
+        let input = "340282366920938463463374607431768211456 * ?x";
+        let (expression, _) = parse_big(input).unwrap();
+        let values =
+            vec![BigUint::from_decimal("340282366920938463463374607431768211456").unwrap()];
+        let public_inputs =
+            bigint_circuit::public_inputs::<Fp>(&expression, &values, DEFAULT_VARIABLE_LIMBS)
+                .unwrap();
+        let circuit = BigIntCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
+            variable_limbs: DEFAULT_VARIABLE_LIMBS,
+        };
+        let k = mock_prove(&circuit, public_inputs.clone()).unwrap();
+        let bigint =
+            ProofArtifact::prove(k, ProofMode::BigInt, input, circuit, &public_inputs).unwrap();
+
+        // a reduced fraction, public as numerator and denominator
+        let input = "?a / 4 - 1";
+        let (expression, _) = parse_rational(input).unwrap();
+        let values = vec![Rational::from_token("2").unwrap()];
+        let public_inputs = rational_circuit::public_inputs::<Fp>(&expression, &values).unwrap();
+        assert_eq!(public_inputs, vec![-Fp::one(), Fp::from(2)]);
+        let circuit = RationalCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
+        };
+        let k = mock_prove(&circuit, public_inputs.clone()).unwrap();
+        let rational =
+            ProofArtifact::prove(k, ProofMode::Rational, input, circuit, &public_inputs).unwrap();
+
+        for artifact in [bigint, rational] {
+            // the artifact round trips through its text format
+            let parsed = ProofArtifact::parse(&artifact.to_string()).unwrap();
+            assert_eq!(parsed, artifact);
+            assert!(parsed.verify().is_ok());
+
+            // a different result is not accepted
+            let mut wrong = artifact.clone();
+            wrong.public_inputs[0] = "7".to_string();
+            assert!(matches!(
+                wrong.verify(),
+                Err(ProofError::Circuit(CircuitError::InvalidProof(_)))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_factorization_artifact() {
+        let k = prove_factorization::<Fp>(7, 13, 91).unwrap();
+        let circuit = FactorCircuit {
+            p: Value::known(Fp::from(7)),
+            q: Value::known(Fp::from(13)),
+        };
+        let artifact = ProofArtifact::prove(
+            k,
+            Mode::Integer.into(),
+            "prove ?p * ?q == 91",
+            circuit,
+            &[Fp::from(91)],
+        )
+        .unwrap();
+
+        let parsed = ProofArtifact::parse(&artifact.to_string()).unwrap();
+        assert!(parsed.verify().is_ok());
+
+        // the public input must be the `n` of the statement
+        let other = ProofArtifact {
+            statement: "prove ?p * ?q == 77".to_string(),
+            ..artifact.clone()
+        };
+        assert!(matches!(other.verify(), Err(ProofError::MalformedArtifact)));
+
+        // nor is the proof valid for a different `n`
+        let forged = ProofArtifact {
+            statement: "prove ?p * ?q == 77".to_string(),
+            public_inputs: vec!["77".to_string()],
+            ..artifact
+        };
+        assert!(matches!(
+            forged.verify(),
+            Err(ProofError::Circuit(CircuitError::InvalidProof(_)))
+        ));
+    }
+
+    #[test]
+    fn test_threshold_artifact() {
+        let circuit = ThresholdCircuit {
+            value: Value::known(Fp::from(1500)),
+            threshold: Value::known(Fp::from(1000)),
+        };
+        let k = prove_threshold::<Fp>(1500, 1000).unwrap();
+        let artifact = ProofArtifact::prove(
+            k,
+            Mode::Integer.into(),
+            "prove ?x >= 1000",
+            circuit,
+            &[Fp::from(1000)],
+        )
+        .unwrap();
+
+        let parsed = ProofArtifact::parse(&artifact.to_string()).unwrap();
+        assert_eq!(parsed.statement, "prove ?x >= 1000");
+        assert!(parsed.verify().is_ok());
+
+        // the public input must be the threshold of the statement
+        let lowered = ProofArtifact {
+            statement: "prove ?x >= 999".to_string(),
+            ..artifact.clone()
+        };
+        assert!(matches!(
+            lowered.verify(),
+            Err(ProofError::MalformedArtifact)
+        ));
+
+        // nor is the proof valid for a different threshold
+        let raised = ProofArtifact {
+            statement: "prove ?x >= 2000".to_string(),
+            public_inputs: vec!["2000".to_string()],
+            ..artifact
+        };
+        assert!(matches!(
+            raised.verify(),
+            Err(ProofError::Circuit(CircuitError::InvalidProof(_)))
+        ));
+    }
+}
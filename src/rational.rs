@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::{
+    errors::ParserError,
+    expression::Expression,
+    zk_calculator::{FromToken, Operator},
+};
+
+/// Separator of a fraction's numerator and denominator, e.g. `3/4`.
+const FRACTION_BAR: char = '/';
+
+/// Reduced fraction.
+/// The numerator is an i64 and the denominator a positive i64, coprime to
+/// the numerator, so every rational number has exactly one representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rational {
+    /// Signed numerator.
+    pub numerator: i64,
+    /// Positive denominator.
+    pub denominator: i64,
+}
+
+/// Reduction witness of a fraction `p/q`.
+/// `p = g·p'` and `q = g·q'` with `q' > 0`, and `x·p' + y·q' = 1` proves the
+/// reduced parts coprime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reduction {
+    /// Common divisor, carrying the sign of the denominator.
+    pub gcd: i128,
+    /// Reduced numerator `p'`.
+    pub numerator: i128,
+    /// Reduced, positive denominator `q'`.
+    pub denominator: i128,
+    /// Bezout coefficient of the reduced numerator.
+    pub x: i128,
+    /// Bezout coefficient of the reduced denominator.
+    pub y: i128,
+}
+
+/// Reduction implementation.
+impl Reduction {
+    /// Reduces a fraction by the gcd of its parts.
+    /// Returns `None` if the denominator is zero.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        // `|p|·s + |q|·t = gcd(|p|, |q|)`, so dividing by the gcd leaves a
+        // Bezout relation of the reduced parts, up to their signs
+        let (gcd, s, t) = extended_gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let gcd = gcd as i128 * denominator.signum();
+
+        Some(Self {
+            gcd,
+            numerator: numerator / gcd,
+            denominator: denominator / gcd,
+            x: s * numerator.signum() * denominator.signum(),
+            y: t,
+        })
+    }
+}
+
+/// Rational implementation.
+impl Rational {
+    /// Creates the reduced fraction `numerator/denominator`.
+    /// Returns `None` if the denominator is zero or a reduced part does not
+    /// fit into an i64.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        let reduction = Reduction::new(numerator, denominator)?;
+
+        Some(Self {
+            numerator: i64::try_from(reduction.numerator).ok()?,
+            denominator: i64::try_from(reduction.denominator).ok()?,
+        })
+    }
+
+    /// Applies a `+`, `-`, `*` or `/` operator by cross multiplication.
+    /// Returns `None` on division by zero or overflow.
+    pub fn apply(&self, operator: Operator, rhs: &Rational) -> Option<Self> {
+        let (a, b) = (self.numerator as i128, self.denominator as i128);
+        let (c, d) = (rhs.numerator as i128, rhs.denominator as i128);
+
+        // the parts are i64, so no product or sum overflows an i128
+        match operator {
+            Operator::Add => Self::new(a * d + c * b, b * d),
+            Operator::Sub => Self::new(a * d - c * b, b * d),
+            Operator::Mul => Self::new(a * c, b * d),
+            Operator::Div => Self::new(a * d, b * c),
+            _ => None,
+        }
+    }
+
+    /// Evaluates a rational expression, given the values of its variables.
+    /// Returns `None` on division by zero or overflow.
+    pub fn evaluate(expression: &Expression, variables: &[Rational]) -> Option<Self> {
+        match expression {
+            Expression::Constant(constant) => Self::new(*constant as i128, 1),
+            Expression::Variable(index) => Some(variables[*index]),
+            Expression::Binary(operator, lhs, rhs) => {
+                Self::evaluate(lhs, variables)?.apply(*operator, &Self::evaluate(rhs, variables)?)
+            }
+            // the parser only accepts arithmetic operators
+            _ => None,
+        }
+    }
+}
+
+/// FromToken implementation for Rational.
+impl FromToken<Rational, ParserError> for Rational {
+    /// Parses an integer or a fraction, e.g. `-3` or `6/8`, into its reduced
+    /// form.
+    fn from_token(token: &str) -> Result<Rational, ParserError> {
+        let (numerator, denominator) = token.split_once(FRACTION_BAR).unwrap_or((token, "1"));
+
+        let numerator = numerator
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| ParserError::InvalidOperand)?;
+        let denominator = denominator
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| ParserError::InvalidOperand)?;
+
+        Rational::new(numerator as i128, denominator as i128).ok_or(ParserError::InvalidOperand)
+    }
+}
+
+/// Formats the fraction as `numerator/denominator`, e.g. `-3/4`.
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.numerator, FRACTION_BAR, self.denominator)
+    }
+}
+
+/// Returns `(gcd, s, t)` with `a·s + b·t = gcd(a, b)`, by the extended
+/// Euclidean algorithm.
+pub fn extended_gcd(a: u128, b: u128) -> (u128, i128, i128) {
+    // the coefficients stay below `max(a, b) / gcd` in magnitude, so the
+    // parts of a cross product fit
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (1i128, 0i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q as i128 * s1);
+        (t0, t1) = (t1, t0 - q as i128 * t1);
+    }
+
+    (r0, s0, t0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::from_token("2/4").unwrap();
+        let third = Rational::from_token("-1/-3").unwrap();
+        assert_eq!(half.to_string(), "1/2");
+        assert_eq!(third.to_string(), "1/3");
+        assert_eq!(Rational::from_token("6/-8").unwrap().to_string(), "-3/4");
+        assert_eq!(Rational::from_token("0/5").unwrap().to_string(), "0/1");
+
+        assert_eq!(
+            half.apply(Operator::Add, &third).unwrap().to_string(),
+            "5/6"
+        );
+        assert_eq!(half.apply(Operator::Sub, &half).unwrap().to_string(), "0/1");
+        assert_eq!(
+            half.apply(Operator::Mul, &third).unwrap().to_string(),
+            "1/6"
+        );
+        assert_eq!(
+            half.apply(Operator::Div, &third).unwrap().to_string(),
+            "3/2"
+        );
+
+        let zero = Rational::new(0, 1).unwrap();
+        assert_eq!(half.apply(Operator::Div, &zero), None);
+        for input in ["1/0", "1/", "1.5", "9223372036854775808"] {
+            assert!(matches!(
+                Rational::from_token(input),
+                Err(ParserError::InvalidOperand)
+            ));
+        }
+
+        // the reduction carries the sign of the denominator in the gcd, and
+        // its Bezout relation holds
+        for (p, q) in [(6, -8), (-7, 3), (-4, -6), (0, 5), (i64::MIN as i128, 1)] {
+            let r = Reduction::new(p, q).unwrap();
+            assert_eq!(r.gcd * r.numerator, p);
+            assert_eq!(r.gcd * r.denominator, q);
+            assert!(r.denominator > 0);
+            assert_eq!(r.x * r.numerator + r.y * r.denominator, 1);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    chips::{
+        arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input},
+        rational::{Fraction, RationalInstructions},
+    },
+    expression::Expression,
+    field,
+    rational::Rational,
+    zk_calculator::Operator,
+};
+
+/// Rational circuit definition.
+///
+/// Computes a `+`, `-`, `*` and `/` expression over exact fractions, every
+/// value a numerator and denominator pair reduced after each operator. The
+/// reduced numerator and denominator of the result are the public inputs.
+pub struct RationalCircuit {
+    /// Expression to compute.
+    pub expression: Expression,
+    /// Private variable values.
+    pub variables: Vec<Value<Rational>>,
+}
+
+/// Rational circuit implementation.
+impl<F: FieldExt> Circuit<F> for RationalCircuit {
+    // reuse the top-level config
+    type Config = ArithmeticConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            expression: self.expression.clone(),
+            variables: vec![Value::unknown(); self.variables.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // get advice columns
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        // get instance column
+        let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
+
+        // reuse the ArithmeticChip configuration and return
+        ArithmeticChip::configure(meta, a, b, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // construct the arithmetic chip
+        let arithmetic_chip = ArithmeticChip::<F>::construct(config, ());
+
+        // witness each variable directly into a reduction, so its parts are
+        // bounded like the result of any operator
+        let mut variables = Vec::new();
+        for value in &self.variables {
+            let numerator = value.map(|v| field::from_signed(v.numerator as i128));
+            let denominator = value.map(|v| field::from_signed(v.denominator as i128));
+            let (numerator, denominator) = arithmetic_chip.reduce(
+                &mut layouter,
+                (Input::Private(numerator), Input::Private(denominator)),
+            )?;
+            variables.push((Input::from(numerator), Input::from(denominator)));
+        }
+
+        // compile the expression
+        let (numerator, denominator) = Self::compile(
+            &arithmetic_chip,
+            &mut layouter,
+            &self.expression,
+            &variables,
+        )?;
+
+        // expose the numerator and the denominator of the result
+        for (row, part) in [numerator, denominator].into_iter().enumerate() {
+            let part = match part {
+                Input::Number(part) => part,
+                Input::Private(part) => {
+                    arithmetic_chip.load_private(layouter.namespace(|| "load part"), part)?
+                }
+                // a lone constant is not consumed by any instruction, so load
+                // it
+                Input::Constant(part) => {
+                    arithmetic_chip.load_constant(layouter.namespace(|| "load part"), part)?
+                }
+            };
+            arithmetic_chip.expose_public(layouter.namespace(|| "expose part"), part, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Rational circuit compiler.
+impl RationalCircuit {
+    /// Compiles an expression into rational instructions and returns the
+    /// reduced numerator and denominator of its result.
+    fn compile<F: FieldExt>(
+        arithmetic_chip: &ArithmeticChip<F>,
+        layouter: &mut impl Layouter<F>,
+        expression: &Expression,
+        variables: &[Fraction<F>],
+    ) -> Result<Fraction<F>, Error> {
+        match expression {
+            // an integer is already reduced over one
+            Expression::Constant(constant) => Ok((
+                Input::Constant(F::from(*constant)),
+                Input::Constant(F::one()),
+            )),
+            Expression::Variable(index) => Ok(variables[*index].clone()),
+            Expression::Binary(operator, lhs, rhs) => {
+                let a = Self::compile(arithmetic_chip, layouter, lhs, variables)?;
+                let b = Self::compile(arithmetic_chip, layouter, rhs, variables)?;
+
+                let (numerator, denominator) = match operator {
+                    Operator::Add => arithmetic_chip.rational_add(layouter, a, b)?,
+                    Operator::Sub => arithmetic_chip.rational_sub(layouter, a, b)?,
+                    Operator::Mul => arithmetic_chip.rational_mul(layouter, a, b)?,
+                    Operator::Div => arithmetic_chip.rational_div(layouter, a, b)?,
+                    // the parser only accepts `+`, `-`, `*` and `/`
+                    _ => return Err(Error::Synthesis),
+                };
+
+                Ok((numerator.into(), denominator.into()))
+            }
+            // the parser only accepts binary operators
+            _ => Err(Error::Synthesis),
+        }
+    }
+}
+
+/// Returns the reduced numerator and denominator of the result of a rational
+/// expression as public inputs, in the layout `RationalCircuit` exposes them.
+/// Returns `None` if the result is undefined.
+pub fn public_inputs<F: FieldExt>(
+    expression: &Expression,
+    variables: &[Rational],
+) -> Option<Vec<F>> {
+    let c = Rational::evaluate(expression, variables)?;
+
+    Some(vec![
+        field::from_signed(c.numerator as i128),
+        field::from_signed(c.denominator as i128),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expression::parse_rational, zk_calculator::FromToken};
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    fn build(input: &str, values: &[&str]) -> RationalCircuit {
+        let (expression, variables) = parse_rational(input).unwrap();
+        assert_eq!(variables.len(), values.len());
+
+        RationalCircuit {
+            expression,
+            variables: values
+                .iter()
+                .map(|v| Value::known(Rational::from_token(v).unwrap()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_rational() {
+        let k = 11;
+
+        for (input, values, c) in [
+            ("?a + ?b", vec!["1/2", "1/3"], "5/6"),
+            ("?a - ?b * 2", vec!["1/2", "3/4"], "-1/1"),
+            ("(?a + 1) / ?b", vec!["-2/6", "4/3"], "1/2"),
+            ("-(?a - ?b) / -2", vec!["1/2", "3/4"], "-1/8"),
+            ("1 / 3 + 2 / 3", vec![], "1/1"),
+        ] {
+            let circuit = build(input, &values);
+            let c = Rational::from_token(c).unwrap();
+            let public_inputs = vec![
+                field::from_signed::<Fp>(c.numerator as i128),
+                Fp::from(c.denominator as u64),
+            ];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            // the same value in unreduced form is not accepted
+            let unreduced = public_inputs.iter().map(|part| part.double()).collect();
+            let prover = MockProver::run(k, &circuit, vec![unreduced]).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        // division by zero has no reduction
+        let circuit = build("?a / ?b", &["1/2", "0"]);
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::zero(), Fp::one()]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
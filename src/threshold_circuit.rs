@@ -0,0 +1,126 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::chips::{
+    arithmetic::{ArithmeticChip, ArithmeticConfig, ArithmeticInstructions, Input},
+    compare::BITS,
+    range::RangeInstructions,
+    sub::SubInstructions,
+};
+
+/// Threshold circuit definition.
+///
+/// Proves the statement `?x >= threshold` for a private u64 `x` without
+/// revealing it. The only public input is the threshold (row zero).
+/// `x` and `x - threshold` are both range checked to u64, so `x` is at least
+/// the threshold.
+pub struct ThresholdCircuit<F: FieldExt> {
+    /// Private value.
+    pub value: Value<F>,
+    /// Public threshold, constrained to the instance column.
+    pub threshold: Value<F>,
+}
+
+/// Threshold circuit implementation.
+impl<F: FieldExt> Circuit<F> for ThresholdCircuit<F> {
+    // reuse the top-level config
+    type Config = ArithmeticConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            value: Value::default(),
+            threshold: Value::default(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // get advice columns
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        // get instance column
+        let instance = meta.instance_column();
+        // get constant column
+        let constant = meta.fixed_column();
+
+        // reuse the ArithmeticChip configuration and return
+        ArithmeticChip::configure(meta, a, b, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // construct the arithmetic chip
+        let arithmetic_chip = ArithmeticChip::<F>::construct(config, ());
+
+        // witness the value directly into its range check, so it is a u64
+        let value = arithmetic_chip.range_check(&mut layouter, Input::Private(self.value), BITS)?;
+
+        // load the threshold and constrain it to the public input
+        let threshold = arithmetic_chip
+            .load_private(layouter.namespace(|| "load threshold"), self.threshold)?;
+        arithmetic_chip.expose_public(
+            layouter.namespace(|| "expose threshold"),
+            threshold.clone(),
+            0,
+        )?;
+
+        // the difference is a u64 only if the value is at least the threshold
+        let difference = arithmetic_chip.sub(&mut layouter, value.into(), threshold.into())?;
+        arithmetic_chip.range_check(&mut layouter, difference.into(), BITS)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    #[test]
+    fn test_threshold() {
+        let k = 8;
+
+        for (value, threshold, holds) in [
+            (1000, 1000, true),
+            (1001, 1000, true),
+            (u64::MAX, 0, true),
+            (u64::MAX, u64::MAX, true),
+            (999, 1000, false),
+            (0, u64::MAX, false),
+        ] {
+            let circuit = ThresholdCircuit {
+                value: Value::known(Fp::from(value)),
+                threshold: Value::known(Fp::from(threshold)),
+            };
+
+            let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(threshold)]]).unwrap();
+            assert_eq!(prover.verify().is_ok(), holds);
+        }
+
+        // the threshold is bound to the public input
+        let circuit = ThresholdCircuit {
+            value: Value::known(Fp::from(1000)),
+            threshold: Value::known(Fp::from(1000)),
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(1001)]]).unwrap();
+        assert!(prover.verify().is_err());
+
+        // a value outside of u64 is rejected, even though `x - threshold` is
+        // a u64
+        let circuit = ThresholdCircuit {
+            value: Value::known(Fp::from_u128(u64::MAX as u128 + 5)),
+            threshold: Value::known(Fp::from(u64::MAX)),
+        };
+
+        let prover = MockProver::run(k, &circuit, vec![vec![Fp::from(u64::MAX)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+}
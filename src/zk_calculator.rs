@@ -1,15 +1,39 @@
-use std::io;
+use std::{fmt, io, ops::RangeInclusive, path::PathBuf};
 
-use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::Value,
+    dev::MockProver,
+    plonk::{Circuit, Error},
+};
 
 use crate::{
-    calculator_circuit::CalculatorCircuit,
+    bigint::BigUint,
+    bigint_circuit::{self, BigIntCircuit, DEFAULT_VARIABLE_LIMBS},
+    calculator_circuit::{CalculatorCircuit, DEFAULT_EXPONENT_BITS},
     errors::{CircuitError, ParserError},
+    expression::{self, BigExpression, Expression},
+    factor_circuit::FactorCircuit,
+    field::{self, ProvingField},
+    fixed::{FixedPoint, Rounding},
+    proof::{ProofArtifact, ProofMode},
+    rational::Rational,
+    rational_circuit::{self, RationalCircuit},
+    threshold_circuit::ThresholdCircuit,
 };
 
+/// Smallest circuit size attempted, `2**4` rows.
+const MIN_K: u32 = 4;
+
+/// Largest circuit size attempted, `2**18` rows.
+const MAX_K: u32 = 18;
+
+/// Prefix of a statement, e.g. `prove ?x >= 1000` or `prove ?p * ?q == 91`.
+pub const PROVE_PREFIX: &str = "prove ";
+
 /// Valid operators for the ZkCalculator.
 /// Note that other operators are not implemented due to complexity.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum Operator {
     /// Addition operator.
     Add,
@@ -17,10 +41,150 @@ pub enum Operator {
     Sub,
     /// Multiplication operator.
     Mul,
+    /// Field division operator, multiplies by the inverse of the divisor.
+    /// Only available in field mode.
+    Div,
+    /// Exponentiation operator, the exponent is public or private.
+    Pow,
+    /// Less than operator, returns a boolean.
+    Lt,
+    /// Less than or equal operator, returns a boolean.
+    Le,
+    /// Greater than operator, returns a boolean.
+    Gt,
+    /// Greater than or equal operator, returns a boolean.
+    Ge,
+    /// Equality operator, returns a boolean.
+    Eq,
+    /// Inequality operator, returns a boolean.
+    Ne,
+    /// Logical and operator over booleans.
+    And,
+    /// Logical or operator over booleans.
+    Or,
+    /// Bitwise and operator over u64.
+    BitAnd,
+    /// Bitwise or operator over u64.
+    BitOr,
+    /// Bitwise xor operator over u64, spelled `xor` since `^` is
+    /// exponentiation.
+    BitXor,
+    /// Left shift operator over u64, the shift is a public constant.
+    Shl,
+    /// Right shift operator over u64, the shift is a public constant.
+    Shr,
+    /// Wrapping addition operator over u64, modulo 2^64.
+    WrappingAdd,
+    /// Wrapping subtraction operator over u64, modulo 2^64.
+    WrappingSub,
+    /// Wrapping multiplication operator over u64, modulo 2^64.
+    WrappingMul,
+    /// Saturating addition operator over u64, clamped to `u64::MAX`.
+    SaturatingAdd,
+    /// Saturating subtraction operator over u64, clamped to `0`.
+    SaturatingSub,
+    /// Saturating multiplication operator over u64, clamped to `u64::MAX`.
+    SaturatingMul,
+    /// Modulus postfix `a mod m`, for a public constant `m` of at least one.
+    /// Additions, subtractions, multiplications and public powers of its
+    /// left hand side are reduced modulo `m` after every step.
+    Mod,
+}
+
+/// Operator implementation.
+impl Operator {
+    /// Returns the binding power of the operator, higher binds tighter.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Operator::Or => 1,
+            Operator::And => 2,
+            Operator::BitOr => 3,
+            Operator::BitXor => 4,
+            Operator::BitAnd => 5,
+            Operator::Eq | Operator::Ne => 6,
+            Operator::Lt | Operator::Le | Operator::Gt | Operator::Ge => 7,
+            Operator::Mod => 8,
+            Operator::Shl | Operator::Shr => 9,
+            Operator::Add
+            | Operator::Sub
+            | Operator::WrappingAdd
+            | Operator::WrappingSub
+            | Operator::SaturatingAdd
+            | Operator::SaturatingSub => 10,
+            Operator::Mul | Operator::Div | Operator::WrappingMul | Operator::SaturatingMul => 11,
+            Operator::Pow => 12,
+        }
+    }
+
+    /// Returns true if the operator looks up bytes in the bitwise table.
+    pub fn is_bitwise(&self) -> bool {
+        matches!(self, Operator::BitAnd | Operator::BitOr | Operator::BitXor)
+    }
+
+    /// Returns true if the operator is a pure field operation, the only
+    /// operators of field mode.
+    pub fn is_field(&self) -> bool {
+        matches!(
+            self,
+            Operator::Add | Operator::Sub | Operator::Mul | Operator::Div | Operator::Pow
+        )
+    }
+
+    /// Returns true if the operator associates to the right, so `a ^ b ^ c`
+    /// is `a ^ (b ^ c)`.
+    pub fn is_right_associative(&self) -> bool {
+        matches!(self, Operator::Pow)
+    }
+}
+
+/// Functions of the ZkCalculator, called as `name(arguments)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    /// Integer square root over u64, rounded down.
+    Sqrt,
+    /// Greatest common divisor of two u64, `gcd(0, 0)` is `0`.
+    Gcd,
+    /// Least common multiple of two u64, which must fit into a u64.
+    Lcm,
+    /// Absolute value of a signed integer, negative values being the field
+    /// negation of their magnitude, which must fit into a u64.
+    Abs,
+    /// Minimum of two or more u64.
+    Min,
+    /// Maximum of two or more u64.
+    Max,
+}
+
+/// Function implementation.
+impl Function {
+    /// Returns the range of the number of arguments of the function.
+    pub fn arity(&self) -> RangeInclusive<usize> {
+        match self {
+            Function::Sqrt | Function::Abs => 1..=1,
+            Function::Gcd | Function::Lcm => 2..=2,
+            Function::Min | Function::Max => 2..=usize::MAX,
+        }
+    }
+}
+
+/// FromToken implementation for Function.
+impl FromToken<Function, ParserError> for Function {
+    /// Parses a function name, e.g. `sqrt`.
+    fn from_token(token: &str) -> Result<Function, ParserError> {
+        match token {
+            "sqrt" => Ok(Function::Sqrt),
+            "gcd" => Ok(Function::Gcd),
+            "lcm" => Ok(Function::Lcm),
+            "abs" => Ok(Function::Abs),
+            "min" => Ok(Function::Min),
+            "max" => Ok(Function::Max),
+            _ => Err(ParserError::InvalidFunction),
+        }
+    }
 }
 
 /// Trait to facilitate parsing from a string slice to the desired Type.
-trait FromToken<T, E> {
+pub trait FromToken<T, E> {
     /// Parses a string slice into a given type.
     fn from_token(token: &str) -> Result<T, E>;
 }
@@ -33,13 +197,35 @@ impl FromToken<Operator, ParserError> for Operator {
             "+" => Ok(Operator::Add),
             "-" => Ok(Operator::Sub),
             "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            "^" => Ok(Operator::Pow),
+            "<" => Ok(Operator::Lt),
+            "<=" => Ok(Operator::Le),
+            ">" => Ok(Operator::Gt),
+            ">=" => Ok(Operator::Ge),
+            "==" => Ok(Operator::Eq),
+            "!=" => Ok(Operator::Ne),
+            "&&" => Ok(Operator::And),
+            "||" => Ok(Operator::Or),
+            "&" => Ok(Operator::BitAnd),
+            "|" => Ok(Operator::BitOr),
+            "xor" => Ok(Operator::BitXor),
+            "<<" => Ok(Operator::Shl),
+            ">>" => Ok(Operator::Shr),
+            "+%" => Ok(Operator::WrappingAdd),
+            "-%" => Ok(Operator::WrappingSub),
+            "*%" => Ok(Operator::WrappingMul),
+            "sat+" => Ok(Operator::SaturatingAdd),
+            "sat-" => Ok(Operator::SaturatingSub),
+            "sat*" => Ok(Operator::SaturatingMul),
+            "mod" => Ok(Operator::Mod),
             _ => Err(ParserError::InvalidOperator),
         }
     }
 }
 
 /// Type alias for u64 because i wanna.
-type Operand = u64;
+pub type Operand = u64;
 
 /// FromToken implementation for Operand.
 impl FromToken<Operand, ParserError> for Operand {
@@ -53,27 +239,99 @@ impl FromToken<Operand, ParserError> for Operand {
     }
 }
 
+/// Calculator mode, selects how expressions and private values are parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Integer literals below the field modulus and u64 values.
+    Integer,
+    /// Field element literals and values, only field operators.
+    Field,
+    /// Fixed-point decimal literals and values in the given format.
+    Decimal(FixedPoint),
+}
+
+/// Mode implementation.
+impl Mode {
+    /// Returns the mode of a name, as formatted by `Display`, e.g. `integer`
+    /// or `decimal 6 half-up`.
+    pub fn from_name(name: &str) -> Option<Mode> {
+        match name.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["integer"] => Some(Mode::Integer),
+            ["field"] => Some(Mode::Field),
+            ["decimal", decimals, rounding] => {
+                let rounding = Rounding::from_token(rounding).ok()?;
+                FixedPoint::new(decimals.parse().ok()?, rounding)
+                    .ok()
+                    .map(Mode::Decimal)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a string slice into an Expression and its variable names.
+    pub fn parse<F: FieldExt>(
+        &self,
+        input: &str,
+    ) -> Result<(Expression, Vec<String>), ParserError> {
+        match self {
+            Mode::Integer => expression::parse_integer::<F>(input),
+            Mode::Field => expression::parse_field::<F>(input),
+            Mode::Decimal(format) => expression::parse_fixed(input, *format),
+        }
+    }
+
+    /// Formats a result of the mode.
+    pub fn format<F: FieldExt>(&self, value: &F) -> String {
+        match self {
+            Mode::Integer => format!("{:#?}", value),
+            Mode::Field => field::to_canonical(value),
+            Mode::Decimal(format) => format.format(value),
+        }
+    }
+}
+
+/// Formats the mode by name, the format of decimal mode included.
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Integer => write!(f, "integer"),
+            Mode::Field => write!(f, "field"),
+            Mode::Decimal(format) => write!(f, "decimal {}", format),
+        }
+    }
+}
+
 /// Complete Operation.
-struct Operation {
-    /// Input a (lhs).
-    pub a: Operand,
-    /// Input b (rhs).
-    pub b: Operand,
-    /// Operator.
-    pub operator: Operator,
+struct Operation<F: ProvingField> {
+    /// Mode the input is parsed in.
+    pub mode: Mode,
+    /// User input the expression is parsed from.
+    pub input: String,
+    /// Expression to compute.
+    pub expression: Expression,
+    /// Names of the private variables referenced by the expression.
+    pub variables: Vec<String>,
+    /// Values of the private variables.
+    pub values: Vec<F>,
 }
 
-/// ZkCalculator definition.
-pub struct ZkCalculator {
+/// ZkCalculator definition, over the field `F`.
+pub struct ZkCalculator<F: ProvingField> {
     /// Optionally stores the Operation to execute.
-    operation: Option<Operation>,
+    operation: Option<Operation<F>>,
+    /// Optionally writes a real proof artifact of the calculation to this
+    /// path.
+    proof_path: Option<PathBuf>,
 }
 
 /// ZkCalculator ipmlementation.
-impl ZkCalculator {
+impl<F: ProvingField> ZkCalculator<F> {
     /// Creates a new ZkCalculator with no operation defined.
-    pub fn new() -> Self {
-        Self { operation: None }
+    pub fn new(proof_path: Option<PathBuf>) -> Self {
+        Self {
+            operation: None,
+            proof_path,
+        }
     }
 
     /// Runs the ZkCalculator Program.
@@ -83,11 +341,76 @@ impl ZkCalculator {
         let mut input = String::new();
         println!("\n\n/- ---------------------------------------------- -/");
         println!("/- enter calculation to perforn (format: `a + b`) -/");
+        println!(
+            "/- or a statement to prove (format: `prove ?x >= 1000` or `prove ?p * ?q == 91`) -/"
+        );
         // panics if io fails
         io::stdin().read_line(&mut input).expect("io failed");
 
+        // a statement proves a claim about private values instead of
+        // computing a result
+        if let Some(statement) = input.trim().strip_prefix(PROVE_PREFIX) {
+            // a factorization statement proves knowledge of two factors
+            if let Ok((p, q, n)) = expression::parse_factorization(statement) {
+                // get the private factors, panics if io or parsing fails
+                let p_value = read_value(&p).expect("parse failed");
+                let q_value = read_value(&q).expect("parse failed");
+
+                // prove the statement, panics if the circuit fails
+                let k = prove_factorization::<F>(p_value, q_value, n).expect("circuit failed");
+
+                // prove for real at the size the mock prover found, panics if
+                // the circuit or io fails
+                let circuit = FactorCircuit {
+                    p: Value::known(F::from(p_value)),
+                    q: Value::known(F::from(q_value)),
+                };
+                self.write_artifact(k, Mode::Integer.into(), &input, circuit, &[F::from(n)])
+                    .expect("circuit failed");
+
+                println!(
+                    "proof generation successful!\nstatement: ?{} * ?{} == {}",
+                    p, q, n
+                );
+                return;
+            }
+
+            // otherwise it is a threshold statement, panics if parsing fails
+            let (name, threshold) = expression::parse_threshold(statement).expect("parse failed");
+
+            // get the private value, panics if io or parsing fails
+            let value = read_value(&name).expect("parse failed");
+
+            // prove the statement, panics if the circuit fails
+            let k = prove_threshold::<F>(value, threshold).expect("circuit failed");
+
+            // prove for real at the size the mock prover found, panics if the
+            // circuit or io fails
+            let circuit = ThresholdCircuit {
+                value: Value::known(F::from(value)),
+                threshold: Value::known(F::from(threshold)),
+            };
+            self.write_artifact(
+                k,
+                Mode::Integer.into(),
+                &input,
+                circuit,
+                &[F::from(threshold)],
+            )
+            .expect("circuit failed");
+
+            println!(
+                "proof generation successful!\nstatement: ?{} >= {}",
+                name, threshold
+            );
+            return;
+        }
+
         // parse input, panics if parsing fails
-        self.parse(input).expect("parse failed");
+        self.parse(Mode::Integer, input).expect("parse failed");
+
+        // get the private variable values, panics if io or parsing fails
+        self.read_variables().expect("parse failed");
 
         // run the circuit, panics if circuit fails
         let output = self.run_circuit().expect("circuit failed");
@@ -97,88 +420,403 @@ impl ZkCalculator {
         println!("proof generation successful!\nresult: {:#?}", output);
     }
 
+    /// Runs the ZkCalculator Program over arbitrary-precision integers.
+    /// NOTE: All error code paths should panic here.
+    pub fn run_bigint(&mut self) {
+        // get user input.
+        let mut input = String::new();
+        println!("\n\n/- ---------------------------------------------- -/");
+        println!("/- enter big integer calculation (format: `a * b`) -/");
+        println!("/- only `+` and `*` are supported -/");
+        // panics if io fails
+        io::stdin().read_line(&mut input).expect("io failed");
+
+        // parse input, panics if parsing fails
+        let (expression, variables) = expression::parse_big(&input).expect("parse failed");
+
+        // get the private variable values, panics if io or parsing fails
+        let values = variables
+            .iter()
+            .map(|name| read_big_value(name))
+            .collect::<Result<Vec<BigUint>, ParserError>>()
+            .expect("parse failed");
+
+        // prove the calculation, panics if the circuit fails
+        let output = self
+            .run_bigint_circuit(&input, expression, values)
+            .expect("circuit failed");
+
+        println!("proof generation successful!\nresult: {}", output);
+    }
+
+    /// Runs the ZkCalculator Program over exact fractions.
+    /// NOTE: All error code paths should panic here.
+    pub fn run_rational(&mut self) {
+        // get user input.
+        let mut input = String::new();
+        println!("\n\n/- ---------------------------------------------- -/");
+        println!("/- enter rational calculation (format: `a / b`) -/");
+        println!("/- only `+`, `-`, `*` and `/` are supported -/");
+        // panics if io fails
+        io::stdin().read_line(&mut input).expect("io failed");
+
+        // parse input, panics if parsing fails
+        let (expression, variables) = expression::parse_rational(&input).expect("parse failed");
+
+        // get the private variable values, panics if io or parsing fails
+        let values = variables
+            .iter()
+            .map(|name| read_rational(name))
+            .collect::<Result<Vec<Rational>, ParserError>>()
+            .expect("parse failed");
+
+        // prove the calculation, panics if the circuit fails
+        let output = self
+            .run_rational_circuit(&input, expression, values)
+            .expect("circuit failed");
+
+        println!("proof generation successful!\nresult: {}", output);
+    }
+
+    /// Runs the ZkCalculator Program over raw field elements.
+    /// NOTE: All error code paths should panic here.
+    pub fn run_field(&mut self) {
+        println!("\n\n/- ---------------------------------------------- -/");
+        println!("/- enter field calculation (format: `a / b`) -/");
+        println!("/- only `+`, `-`, `*`, `/` and `^` are supported -/");
+
+        self.run_mode(Mode::Field);
+    }
+
+    /// Runs the ZkCalculator Program over fixed-point decimals.
+    /// NOTE: All error code paths should panic here.
+    pub fn run_decimal(&mut self, format: FixedPoint) {
+        println!("\n\n/- ---------------------------------------------- -/");
+        println!("/- enter decimal calculation (format: `12.50 * 1.08`) -/");
+        println!(
+            "/- only `+`, `-` and `*` are supported, {} decimal places -/",
+            format.decimals
+        );
+
+        self.run_mode(Mode::Decimal(format));
+    }
+
+    /// Reads, proves and prints a calculation in the given mode.
+    /// NOTE: All error code paths should panic here.
+    fn run_mode(&mut self, mode: Mode) {
+        // get user input.
+        let mut input = String::new();
+        // panics if io fails
+        io::stdin().read_line(&mut input).expect("io failed");
+
+        // parse input, panics if parsing fails
+        self.parse(mode, input).expect("parse failed");
+
+        // get the private variable values, panics if io or parsing fails
+        self.read_variables().expect("parse failed");
+
+        // run the circuit, panics if circuit fails
+        let output = self.run_circuit().expect("circuit failed");
+
+        // print the output as the mode formats it
+        println!(
+            "proof generation successful!\nresult: {}",
+            mode.format(&output)
+        );
+    }
+
     /// Parses user input into an Operation and mutates the ZkCalculator.
-    fn parse(&mut self, input: String) -> Result<(), ParserError> {
-        // split input by whitespace
-        let mut tokens = input.split_whitespace();
-
-        // parse into operand a or bubble up error
-        let a = match tokens.next() {
-            Some(a) => Operand::from_token(a),
-            None => Err(ParserError::NotEnoughInputs),
-        }?;
-
-        // parse into operator or bubble up error
-        let operator = match tokens.next() {
-            Some(op) => Operator::from_token(op),
-            None => Err(ParserError::NotEnoughInputs),
-        }?;
-
-        // parse into operand or bubble up error
-        let b = match tokens.next() {
-            Some(b) => Operand::from_token(b),
-            None => Err(ParserError::NotEnoughInputs),
-        }?;
-
-        // if there are more tokens remaining, something went wrong, so we
-        // bubble up an error about it
-        if tokens.next().is_some() {
-            return Err(ParserError::TooManyInputs);
-        }
+    fn parse(&mut self, mode: Mode, input: String) -> Result<(), ParserError> {
+        // parse into an expression and its variables or bubble up error
+        let (expression, variables) = mode.parse::<F>(&input)?;
 
         // mutate the ZkCalculator
-        self.operation = Some(Operation { a, operator, b });
+        self.operation = Some(Operation {
+            mode,
+            input,
+            expression,
+            variables,
+            values: Vec::new(),
+        });
 
         // return ok
         Ok(())
     }
 
-    /// Runs the circuit against a mock prover.
-    fn run_circuit(&self) -> Result<Fp, CircuitError> {
-        // `2**k` must be greater than the number of rows in the circuit,
-        // this circuit only has two rows, so `4` is sufficient
-        let k = 4;
+    /// Reads a value for each private variable of the Operation.
+    fn read_variables(&mut self) -> Result<(), ParserError> {
+        let operation = match self.operation.as_mut() {
+            Some(operation) => operation,
+            None => return Ok(()),
+        };
+
+        for name in &operation.variables {
+            let value = match operation.mode {
+                Mode::Integer => F::from(read_value(name)?),
+                Mode::Field => read_element(name)?,
+                Mode::Decimal(format) => F::from(read_decimal(name, format)?),
+            };
+            operation.values.push(value);
+        }
+
+        Ok(())
+    }
 
+    /// Runs the circuit against a mock prover, then writes a real proof
+    /// artifact if a proof path is set.
+    fn run_circuit(&self) -> Result<F, CircuitError> {
         // get operation
         let operation = self.operation.as_ref().ok_or(CircuitError::NoOperation)?;
 
-        // get operator
-        let operator = operation.operator;
+        // get variable values
+        let values = operation.values.clone();
 
-        // get a and b
-        let a = Fp::from(operation.a);
-        let b = Fp::from(operation.b);
+        // a least common multiple past u64 would only fail verification
+        if operation.expression.lcm_overflows(&values) {
+            return Err(CircuitError::LcmOverflow);
+        }
 
-        // compute c with a and b based on the operator
-        let c = match operator {
-            Operator::Add => a + b,
-            Operator::Sub => a - b,
-            Operator::Mul => a * b,
-        };
+        // compute c by evaluating the expression
+        let c = operation.expression.evaluate(&values);
 
         // create the top-level circuit
         let circuit = CalculatorCircuit {
-            a: Value::known(a),
-            b: Value::known(b),
-            operator,
+            expression: operation.expression.clone(),
+            variables: values.into_iter().map(Value::known).collect(),
+            exponent_bits: DEFAULT_EXPONENT_BITS,
         };
 
         // public input is c
         let public_inputs = vec![c];
 
         // run the mock prover and bubble up any errors
-        let prover = match MockProver::run(k, &circuit, vec![public_inputs.clone()]) {
-            Ok(prover_run) => prover_run,
-            Err(prover_error) => return Err(CircuitError::ProverError(prover_error)),
+        let k = mock_prove(&circuit, public_inputs.clone())?;
+
+        // prove for real at the size the mock prover found
+        self.write_artifact(
+            k,
+            operation.mode.into(),
+            &operation.input,
+            circuit,
+            &public_inputs,
+        )?;
+
+        // return c
+        Ok(c)
+    }
+
+    /// Proves a big integer calculation and returns its result. The limbs of
+    /// the result are the public inputs, so it may exceed the field modulus.
+    fn run_bigint_circuit(
+        &self,
+        input: &str,
+        expression: BigExpression,
+        values: Vec<BigUint>,
+    ) -> Result<BigUint, CircuitError> {
+        // compute the result and its limbs
+        let c = expression.evaluate(&values);
+        let public_inputs =
+            bigint_circuit::public_inputs::<F>(&expression, &values, DEFAULT_VARIABLE_LIMBS)
+                .ok_or(CircuitError::ValueTooLarge)?;
+
+        // create the big integer circuit
+        let circuit = BigIntCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
+            variable_limbs: DEFAULT_VARIABLE_LIMBS,
         };
 
-        // verify the proof and bubble up any errors
-        match prover.verify() {
-            Ok(_) => (),
-            Err(verifier_error) => return Err(CircuitError::VerifierError(verifier_error)),
+        // run the mock prover and bubble up any errors
+        let k = mock_prove(&circuit, public_inputs.clone())?;
+
+        // prove for real at the size the mock prover found
+        self.write_artifact(k, ProofMode::BigInt, input, circuit, &public_inputs)?;
+
+        // return c
+        Ok(c)
+    }
+
+    /// Proves a rational calculation and returns its reduced result. The
+    /// numerator and the denominator of the result are the public inputs.
+    fn run_rational_circuit(
+        &self,
+        input: &str,
+        expression: Expression,
+        values: Vec<Rational>,
+    ) -> Result<Rational, CircuitError> {
+        // compute the result and its parts
+        let c = Rational::evaluate(&expression, &values).ok_or(CircuitError::UndefinedRational)?;
+        let public_inputs = rational_circuit::public_inputs::<F>(&expression, &values)
+            .ok_or(CircuitError::UndefinedRational)?;
+
+        // create the rational circuit
+        let circuit = RationalCircuit {
+            expression,
+            variables: values.into_iter().map(Value::known).collect(),
         };
 
+        // run the mock prover and bubble up any errors
+        let k = mock_prove(&circuit, public_inputs.clone())?;
+
+        // prove for real at the size the mock prover found
+        self.write_artifact(k, ProofMode::Rational, input, circuit, &public_inputs)?;
+
         // return c
         Ok(c)
     }
+
+    /// Proves a circuit for real and writes its artifact, if one is
+    /// requested. Panics if io fails.
+    fn write_artifact<C: Circuit<F>>(
+        &self,
+        k: u32,
+        mode: ProofMode,
+        statement: &str,
+        circuit: C,
+        public_inputs: &[F],
+    ) -> Result<(), CircuitError> {
+        if let Some(path) = &self.proof_path {
+            ProofArtifact::prove(k, mode, statement, circuit, public_inputs)?
+                .write(path)
+                .expect("proof write failed");
+            println!("proof written to {}", path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the value of a private variable from stdin.
+fn read_value(name: &str) -> Result<Operand, ParserError> {
+    // get user input.
+    let mut input = String::new();
+    println!("/- enter private value of `?{}`", name);
+    // panics if io fails
+    io::stdin().read_line(&mut input).expect("io failed");
+
+    // parse into operand or bubble up error
+    Operand::from_token(input.trim())
+}
+
+/// Reads the value of a private field element variable from stdin, decimal or
+/// `0x` hex, a leading `-` means the additive inverse.
+fn read_element<F: FieldExt>(name: &str) -> Result<F, ParserError> {
+    // get user input.
+    let mut input = String::new();
+    println!("/- enter private value of `?{}`", name);
+    // panics if io fails
+    io::stdin().read_line(&mut input).expect("io failed");
+
+    // parse into a field element or bubble up error
+    field::parse_element(input.trim())
+}
+
+/// Reads the value of a private decimal variable from stdin, e.g. `12.50`.
+fn read_decimal(name: &str, format: FixedPoint) -> Result<Operand, ParserError> {
+    // get user input.
+    let mut input = String::new();
+    println!("/- enter private value of `?{}`", name);
+    // panics if io fails
+    io::stdin().read_line(&mut input).expect("io failed");
+
+    // parse into a scaled decimal or bubble up error
+    format.parse_literal(input.trim())
+}
+
+/// Reads the value of a private big integer variable from stdin. Values must
+/// fit into `DEFAULT_VARIABLE_LIMBS` limbs.
+fn read_big_value(name: &str) -> Result<BigUint, ParserError> {
+    // get user input.
+    let mut input = String::new();
+    println!("/- enter private value of `?{}`", name);
+    // panics if io fails
+    io::stdin().read_line(&mut input).expect("io failed");
+
+    // parse into a big integer or bubble up error
+    BigUint::from_decimal(input.trim())
+        .filter(|value| value.limbs().len() <= DEFAULT_VARIABLE_LIMBS)
+        .ok_or(ParserError::InvalidOperand)
+}
+
+/// Reads the value of a private fraction variable from stdin, e.g. `-3/4`.
+fn read_rational(name: &str) -> Result<Rational, ParserError> {
+    // get user input.
+    let mut input = String::new();
+    println!("/- enter private value of `?{}`", name);
+    // panics if io fails
+    io::stdin().read_line(&mut input).expect("io failed");
+
+    // parse into a reduced fraction or bubble up error
+    Rational::from_token(input.trim())
+}
+
+/// Proves that a private value is at least a public threshold, without
+/// revealing the value, and returns the `k` the circuit fits into. Fails
+/// verification if the statement does not hold.
+pub fn prove_threshold<F: FieldExt>(
+    value: Operand,
+    threshold: Operand,
+) -> Result<u32, CircuitError> {
+    // create the threshold circuit
+    let circuit = ThresholdCircuit {
+        value: Value::known(F::from(value)),
+        threshold: Value::known(F::from(threshold)),
+    };
+
+    // public input is the threshold
+    let public_inputs = vec![F::from(threshold)];
+
+    // run the mock prover and bubble up any errors
+    mock_prove(&circuit, public_inputs)
+}
+
+/// Proves knowledge of two private factors `p` and `q`, both greater than
+/// one, whose product is the public `n`, without revealing them, and returns
+/// the `k` the circuit fits into. Fails verification if the statement does
+/// not hold.
+pub fn prove_factorization<F: FieldExt>(
+    p: Operand,
+    q: Operand,
+    n: Operand,
+) -> Result<u32, CircuitError> {
+    // create the factorization circuit
+    let circuit = FactorCircuit {
+        p: Value::known(F::from(p)),
+        q: Value::known(F::from(q)),
+    };
+
+    // public input is `n`
+    let public_inputs = vec![F::from(n)];
+
+    // run the mock prover and bubble up any errors
+    mock_prove(&circuit, public_inputs)
+}
+
+/// Runs and verifies a circuit against a mock prover, returning the `k` it
+/// fits into.
+/// `2**k` must be greater than the number of rows in the circuit, which grows
+/// with the expression, so `k` is increased until the circuit fits.
+pub fn mock_prove<F: FieldExt, C: Circuit<F>>(
+    circuit: &C,
+    public_inputs: Vec<F>,
+) -> Result<u32, CircuitError> {
+    let mut k = MIN_K;
+
+    // run the mock prover and bubble up any errors
+    let prover = loop {
+        match MockProver::run(k, circuit, vec![public_inputs.clone()]) {
+            Ok(prover_run) => break prover_run,
+            // results with many public limbs need more rows as well
+            Err(Error::NotEnoughRowsAvailable { .. } | Error::InstanceTooLarge) if k < MAX_K => {
+                k += 1
+            }
+            Err(prover_error) => return Err(CircuitError::ProverError(prover_error)),
+        }
+    };
+
+    // verify the proof and bubble up any errors
+    match prover.verify() {
+        Ok(_) => Ok(k),
+        Err(verifier_error) => Err(CircuitError::VerifierError(verifier_error)),
+    }
 }
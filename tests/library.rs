@@ -0,0 +1,15 @@
+use halo2_proofs::pasta::Fp;
+
+#[test]
+fn test_prove_threshold() {
+    assert!(zk_calculator::prove_threshold::<Fp>(1500, 1000).is_ok());
+    assert!(zk_calculator::prove_threshold::<Fp>(1000, 1000).is_ok());
+    assert!(zk_calculator::prove_threshold::<Fp>(999, 1000).is_err());
+}
+
+#[test]
+fn test_prove_factorization() {
+    assert!(zk_calculator::prove_factorization::<Fp>(7, 13, 91).is_ok());
+    assert!(zk_calculator::prove_factorization::<Fp>(7, 13, 92).is_err());
+    assert!(zk_calculator::prove_factorization::<Fp>(1, 91, 91).is_err());
+}